@@ -0,0 +1,337 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{
+    errors::{indexing_error::IndexingError, shape_error::ShapeError},
+    DynamicMatrix,
+};
+
+/// A dynamic matrix in stored in column-major order.
+///
+/// Adding a new column is cheap while adding a new row is expensive.
+///
+/// ```
+/// use dynamic_matrix::ColMajorMatrix;
+///
+/// let mut mat = ColMajorMatrix::new([[1, 2], [4, 5]]);
+///
+/// assert_eq!(mat.shape(), (2, 2));
+///
+/// mat.push_col(vec![3, 6]).unwrap();
+/// mat.push_row(vec![7, 8, 9]).unwrap();
+///
+/// assert_eq!(mat.shape(), (3, 3));
+///
+/// assert_eq!(mat[(1, 2)], 6);
+/// assert_eq!(mat.as_slice(), &[1, 4, 7, 2, 5, 8, 3, 6, 9]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColMajorMatrix<T> {
+    data: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> ColMajorMatrix<T> {
+    /// Constructs a new ColMajorMatrix from a nested array of rows
+    ///
+    /// ```
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mat: ColMajorMatrix<isize> = ColMajorMatrix::new([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// assert_eq!(mat.as_slice(), [1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn new<const COLS: usize, const ROWS: usize>(data: [[T; COLS]; ROWS]) -> Self {
+        let mut rows: Vec<_> = data.into_iter().map(|row| row.into_iter()).collect();
+
+        let mut elems = Vec::with_capacity(ROWS * COLS);
+        for _ in 0..COLS {
+            elems.extend(rows.iter_mut().map(|row| row.next().unwrap()));
+        }
+
+        Self {
+            data: elems,
+            rows: ROWS,
+            cols: COLS,
+        }
+    }
+
+    /// Constructs a new empty ColMajorMatrix with a set number of rows
+    ///
+    /// ```
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mat: ColMajorMatrix<isize> = ColMajorMatrix::new_with_rows(3);
+    ///
+    /// assert_eq!(mat.rows(), 3);
+    /// assert_eq!(mat.cols(), 0);
+    /// ```
+    pub fn new_with_rows(rows: usize) -> Self {
+        Self {
+            data: Vec::new(),
+            rows,
+            cols: 0,
+        }
+    }
+
+    /// Returns the number of rows in the ColMajorMatrix
+    ///
+    /// ```
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mat = ColMajorMatrix::new([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(mat.rows(), 2);
+    /// ```
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the ColMajorMatrix
+    ///
+    /// ```
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mat = ColMajorMatrix::new([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(mat.cols(), 3);
+    /// ```
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns a tuple containing the number of rows as the first element and number of columns as the second element
+    ///
+    /// ```
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mat = ColMajorMatrix::new([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// ```
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Appends a new column to the ColMajorMatrix
+    ///
+    /// ```
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mut mat: ColMajorMatrix<isize> = ColMajorMatrix::new_with_rows(3);
+    ///
+    /// mat.push_col(vec![1, 4, 7]).unwrap();
+    /// mat.push_col(vec![2, 5, 8]).unwrap();
+    /// mat.push_col(vec![3, 6, 9]).unwrap();
+    ///
+    /// assert_eq!(mat.as_slice(), [1, 4, 7, 2, 5, 8, 3, 6, 9]);
+    /// assert_eq!(mat.cols(), 3);
+    /// ```
+    ///
+    /// Trying to append a new column with unequal number of rows will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mut mat: ColMajorMatrix<isize> = ColMajorMatrix::new_with_rows(3);
+    ///
+    /// // Trying to push a vector with length 4 into a matrix with only 3 rows
+    /// mat.push_col(vec![1, 2, 3, 4]).unwrap();
+    /// ```
+    pub fn push_col(&mut self, col: Vec<T>) -> Result<(), ShapeError> {
+        if col.len() != self.rows {
+            Err(ShapeError::new_rows_error(self.rows, col.len()))
+        } else {
+            self.data.extend(col);
+            self.cols += 1;
+
+            Ok(())
+        }
+    }
+
+    /// Appends a new row to the ColMajorMatrix
+    ///
+    /// The elements are moved into a freshly allocated buffer in a single pass, so this is O(rows * cols).
+    ///
+    /// ```
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mut mat = ColMajorMatrix::new([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// mat.push_row(vec![7, 8, 9]).unwrap();
+    ///
+    /// assert_eq!(mat.as_slice(), &[1, 4, 7, 2, 5, 8, 3, 6, 9]);
+    /// assert_eq!(mat.rows(), 3);
+    /// ```
+    ///
+    /// Trying to append a new row with unequal number of columns will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mut mat = ColMajorMatrix::new([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// // Trying to push a row with less elements than the number of columns
+    /// mat.push_row(vec![7, 8]).unwrap();
+    /// ```
+    pub fn push_row(&mut self, row: Vec<T>) -> Result<(), ShapeError> {
+        if row.len() != self.cols {
+            Err(ShapeError::new_cols_error(self.cols, row.len()))
+        } else {
+            let mut data = Vec::with_capacity((self.rows + 1) * self.cols);
+            let mut old = std::mem::take(&mut self.data).into_iter();
+            for e in row {
+                data.extend(old.by_ref().take(self.rows));
+                data.push(e);
+            }
+
+            self.data = data;
+            self.rows += 1;
+
+            Ok(())
+        }
+    }
+
+    /// Extracts a slice containing the underlying Vec, in column-major order
+    ///
+    /// ```
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mat = ColMajorMatrix::new([[1, 2], [3, 4]]);
+    ///
+    /// assert_eq!(mat.as_slice(), &[1, 3, 2, 4]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    /// Returns a `Result` containing a shared reference to the value at the given index
+    ///
+    /// ```
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mat = ColMajorMatrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    ///
+    /// for row in 0..mat.rows() {
+    ///     for col in 0..mat.cols() {
+    ///         assert_eq!(*mat.get((row, col)).unwrap(), 3 * row + col + 1);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Indexing outside bounds will return an `IndexingError`.
+    /// ```should_panic
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mat = ColMajorMatrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    ///
+    /// mat.get((3, 3)).unwrap();
+    /// ```
+    pub fn get(&self, index: (usize, usize)) -> Result<&T, IndexingError> {
+        let (row, col) = index;
+        if row < self.rows && col < self.cols {
+            Ok(&self.data[col * self.rows + row])
+        } else {
+            Err(IndexingError::new(index, self.shape()))
+        }
+    }
+
+    /// Returns a `Result` containing an exclusive reference to the value at the given index
+    ///
+    /// ```
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mut mat = ColMajorMatrix::new([[1, 2], [3, 4]]);
+    ///
+    /// *mat.get_mut((0, 1)).unwrap() = 10;
+    ///
+    /// assert_eq!(mat.as_slice(), &[1, 3, 10, 4]);
+    /// ```
+    ///
+    /// Indexing outside bounds will return an `IndexingError`.
+    /// ```should_panic
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mut mat = ColMajorMatrix::new([[1, 2], [3, 4]]);
+    ///
+    /// *mat.get_mut((2, 2)).unwrap() += 1;
+    /// ```
+    pub fn get_mut(&mut self, index: (usize, usize)) -> Result<&mut T, IndexingError> {
+        let (row, col) = index;
+        if row < self.rows && col < self.cols {
+            Ok(&mut self.data[col * self.rows + row])
+        } else {
+            Err(IndexingError::new(index, self.shape()))
+        }
+    }
+
+    /// Converts the ColMajorMatrix into a DynamicMatrix stored in row-major order
+    ///
+    /// ```
+    /// # use dynamic_matrix::{ColMajorMatrix, DynamicMatrix};
+    /// let mat = ColMajorMatrix::new([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// let row_major = mat.to_row_major();
+    ///
+    /// assert_eq!(row_major.shape(), (2, 3));
+    /// assert_eq!(row_major.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(row_major.to_col_major().as_slice(), mat.as_slice());
+    ///
+    /// let empty: ColMajorMatrix<isize> = ColMajorMatrix::new([[], []]);
+    /// assert_eq!(empty.to_row_major().shape(), (2, 0));
+    /// ```
+    pub fn to_row_major(&self) -> DynamicMatrix<T>
+    where
+        T: Clone,
+    {
+        DynamicMatrix::from_fn(self.shape(), |row, col| {
+            self.data[col * self.rows + row].clone()
+        })
+    }
+}
+
+impl<T> DynamicMatrix<T> {
+    /// Converts the DynamicMatrix into a ColMajorMatrix stored in column-major order
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let col_major = mat.to_col_major();
+    ///
+    /// assert_eq!(col_major.shape(), (2, 3));
+    /// assert_eq!(col_major.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    /// assert_eq!(col_major.to_row_major().as_slice(), mat.as_slice());
+    /// ```
+    pub fn to_col_major(&self) -> ColMajorMatrix<T>
+    where
+        T: Clone,
+    {
+        ColMajorMatrix {
+            data: self.cols_iter().flatten().cloned().collect(),
+            rows: self.rows(),
+            cols: self.cols(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for ColMajorMatrix<T> {
+    type Output = T;
+
+    /// Returns a shared reference to the value at the given index
+    ///
+    /// ```
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mat = ColMajorMatrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    ///
+    /// for row in 0..mat.rows() {
+    ///     for col in 0..mat.cols() {
+    ///         assert_eq!(mat[(row, col)], 3 * row + col + 1);
+    ///     }
+    /// }
+    /// ```
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for ColMajorMatrix<T> {
+    /// Returns an exclusive reference to the value at the given index
+    ///
+    /// ```
+    /// # use dynamic_matrix::ColMajorMatrix;
+    /// let mut mat = ColMajorMatrix::new([[1, 2], [3, 4]]);
+    ///
+    /// mat[(1, 0)] = 30;
+    ///
+    /// assert_eq!(mat.as_slice(), &[1, 30, 2, 4]);
+    /// ```
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(index).unwrap()
+    }
+}
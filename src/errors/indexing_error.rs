@@ -1,52 +1,131 @@
 //! Error encountered while indexing into a matrix
 
-use std::fmt;
+use std::{error::Error, fmt};
 
 #[derive(Clone, Debug)]
 /// The error type of indexing out of bounds
+///
+/// ```
+/// # use dynamic_matrix::errors::indexing_error::IndexingError;
+/// use std::error::Error;
+///
+/// let err: Box<dyn Error> = Box::new(IndexingError::new((2, 0), (2, 3)));
+/// assert_eq!(
+///     err.to_string(),
+///     "Attempted indexing row 2. The row index should be in [0, 2)\n"
+/// );
+/// ```
 pub struct IndexingError {
-    row: usize,
-    col: usize,
-    nrows: usize,
-    ncols: usize,
+    kind: IndexingErrorKind,
+}
+
+#[derive(Clone, Debug)]
+enum IndexingErrorKind {
+    Element {
+        row: usize,
+        col: usize,
+        nrows: usize,
+        ncols: usize,
+    },
+    Row {
+        row: usize,
+        nrows: usize,
+    },
+    Col {
+        col: usize,
+        ncols: usize,
+    },
+    Axis {
+        axis: usize,
+        naxes: usize,
+    },
 }
 
 impl fmt::Display for IndexingError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let row_error = if self.row >= self.nrows {
-            Some(format!(
-                "Attemped indexing row {}. The row index should be in [0, {})",
-                self.row, self.nrows
-            ))
-        } else {
-            None
-        };
-
-        let col_error = if self.col >= self.ncols {
-            Some(format!(
-                "Attemped indexing column {}. The columns index should be in [0, {})",
-                self.col, self.ncols
-            ))
-        } else {
-            None
-        };
-
-        match (row_error, col_error) {
-            (Some(re), Some(ce)) => writeln!(f, "{}\n{}", re, ce),
-            (Some(e), None) | (None, Some(e)) => writeln!(f, "{}", e),
-            (None, None) => unreachable!(),
+        match self.kind {
+            IndexingErrorKind::Element {
+                row,
+                col,
+                nrows,
+                ncols,
+            } => {
+                let row_error = if row >= nrows {
+                    Some(format!(
+                        "Attempted indexing row {}. The row index should be in [0, {})",
+                        row, nrows
+                    ))
+                } else {
+                    None
+                };
+
+                let col_error = if col >= ncols {
+                    Some(format!(
+                        "Attempted indexing column {}. The columns index should be in [0, {})",
+                        col, ncols
+                    ))
+                } else {
+                    None
+                };
+
+                match (row_error, col_error) {
+                    (Some(re), Some(ce)) => writeln!(f, "{}\n{}", re, ce),
+                    (Some(e), None) | (None, Some(e)) => writeln!(f, "{}", e),
+                    (None, None) => unreachable!(),
+                }
+            }
+            IndexingErrorKind::Row { row, nrows } => writeln!(
+                f,
+                "Attempted indexing row {}. The row index should be in [0, {})",
+                row, nrows
+            ),
+            IndexingErrorKind::Col { col, ncols } => writeln!(
+                f,
+                "Attempted indexing column {}. The columns index should be in [0, {})",
+                col, ncols
+            ),
+            IndexingErrorKind::Axis { axis, naxes } => writeln!(
+                f,
+                "Attempted indexing axis {}. The axis should be in [0, {})",
+                axis, naxes
+            ),
         }
     }
 }
 
+impl Error for IndexingError {}
+
 impl IndexingError {
     /// Create a new `IndexingError` given the index as a row, column tuple and the shape of the matrix being indexed
     pub fn new(index: (usize, usize), shape: (usize, usize)) -> IndexingError {
         IndexingError {
-            row: index.0,
-            col: index.1,
-            nrows: shape.0,
-            ncols: shape.1,
+            kind: IndexingErrorKind::Element {
+                row: index.0,
+                col: index.1,
+                nrows: shape.0,
+                ncols: shape.1,
+            },
+        }
+    }
+
+    /// Create a new `IndexingError` given the row index and the number of rows of the matrix being indexed
+    pub fn new_row_error(row: usize, nrows: usize) -> IndexingError {
+        IndexingError {
+            kind: IndexingErrorKind::Row { row, nrows },
+        }
+    }
+
+    /// Create a new `IndexingError` given the column index and the number of columns of the matrix being indexed
+    pub fn new_col_error(col: usize, ncols: usize) -> IndexingError {
+        IndexingError {
+            kind: IndexingErrorKind::Col { col, ncols },
+        }
+    }
+
+    /// Create a new `IndexingError` given the axis that was requested and the number of axes of the matrix
+    pub fn new_axis_error(axis: usize, naxes: usize) -> IndexingError {
+        IndexingError {
+            kind: IndexingErrorKind::Axis { axis, naxes },
         }
     }
 }
@@ -0,0 +1,94 @@
+//! Error encountered by operations that can fail for more than one reason
+
+use std::{error::Error, fmt};
+
+use super::shape_error::ShapeError;
+
+#[derive(Clone, Debug)]
+/// The error type for operations that can fail either due to incompatible shapes or due to the contents of the
+/// matrix
+///
+/// ```
+/// # use dynamic_matrix::errors::matrix_error::MatrixError;
+/// use std::error::Error;
+///
+/// let err: Box<dyn Error> = Box::new(MatrixError::new_rank_error(1, 2));
+/// assert_eq!(
+///     err.to_string(),
+///     "The operation performed expected full column rank 2 but the matrix has rank 1.\n"
+/// );
+/// ```
+pub struct MatrixError {
+    kind: MatrixErrorKind,
+}
+
+#[derive(Clone, Debug)]
+enum MatrixErrorKind {
+    Shape(ShapeError),
+    Rank { rank: usize, cols: usize },
+    Singular,
+    NotPositiveDefinite,
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            MatrixErrorKind::Shape(err) => write!(f, "{}", err),
+            MatrixErrorKind::Rank { rank, cols } => writeln!(
+                f,
+                "The operation performed expected full column rank {} but the matrix has rank {}.",
+                cols, rank
+            ),
+            MatrixErrorKind::Singular => writeln!(
+                f,
+                "The operation performed expected a non-singular matrix but the matrix is singular."
+            ),
+            MatrixErrorKind::NotPositiveDefinite => writeln!(
+                f,
+                "The operation performed expected a positive definite matrix but the matrix is not."
+            ),
+        }
+    }
+}
+
+impl Error for MatrixError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            MatrixErrorKind::Shape(err) => Some(err),
+            MatrixErrorKind::Rank { .. }
+            | MatrixErrorKind::Singular
+            | MatrixErrorKind::NotPositiveDefinite => None,
+        }
+    }
+}
+
+impl From<ShapeError> for MatrixError {
+    fn from(err: ShapeError) -> MatrixError {
+        MatrixError {
+            kind: MatrixErrorKind::Shape(err),
+        }
+    }
+}
+
+impl MatrixError {
+    /// Create a new `MatrixError` given the rank of the matrix and the number of columns it was expected to match
+    pub fn new_rank_error(rank: usize, cols: usize) -> MatrixError {
+        MatrixError {
+            kind: MatrixErrorKind::Rank { rank, cols },
+        }
+    }
+
+    /// Create a new `MatrixError` for a square matrix that was expected to be invertible
+    pub fn new_singular_error() -> MatrixError {
+        MatrixError {
+            kind: MatrixErrorKind::Singular,
+        }
+    }
+
+    /// Create a new `MatrixError` for a symmetric matrix that was expected to be positive definite
+    pub fn new_positive_definite_error() -> MatrixError {
+        MatrixError {
+            kind: MatrixErrorKind::NotPositiveDefinite,
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod indexing_error;
+pub mod matrix_error;
 pub mod shape_error;
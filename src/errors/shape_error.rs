@@ -1,62 +1,202 @@
 //! Error encountered due to incompatible shapes
 
-use std::fmt;
+use std::{error::Error, fmt};
 
 #[derive(Clone, Debug)]
 /// The error type for any shape errors
+///
+/// ```
+/// # use dynamic_matrix::errors::shape_error::ShapeError;
+/// use std::error::Error;
+///
+/// let err: Box<dyn Error> = Box::new(ShapeError::new((2, 3), (3, 3)));
+/// assert_eq!(
+///     err.to_string(),
+///     "The operation performed expected 3 rows but the matrix has 2.\n"
+/// );
+/// ```
 pub struct ShapeError {
-    rows: usize,
-    cols: usize,
-    expected_rows: usize,
-    expected_cols: usize,
+    kind: ShapeErrorKind,
+}
+
+#[derive(Clone, Debug)]
+enum ShapeErrorKind {
+    Shape {
+        rows: usize,
+        cols: usize,
+        expected_rows: usize,
+        expected_cols: usize,
+    },
+    Reshape {
+        len: usize,
+        cols: usize,
+    },
+    Len {
+        len: usize,
+        expected_len: usize,
+    },
+    Window {
+        window: usize,
+    },
+    Block {
+        block_rows: usize,
+        block_cols: usize,
+    },
+    InsertRow {
+        row: usize,
+        rows: usize,
+    },
+    InsertCol {
+        col: usize,
+        cols: usize,
+    },
+    MinRows {
+        rows: usize,
+        min_rows: usize,
+    },
 }
 
 impl fmt::Display for ShapeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.rows != self.expected_rows {
-            writeln!(
+        match self.kind {
+            ShapeErrorKind::Shape {
+                rows,
+                cols,
+                expected_rows,
+                expected_cols,
+            } => {
+                if rows != expected_rows {
+                    writeln!(
+                        f,
+                        "The operation performed expected {} rows but the matrix has {}.",
+                        expected_rows, rows
+                    )
+                } else {
+                    writeln!(
+                        f,
+                        "The operation performed expected {} cols but the matrix has {}.",
+                        expected_cols, cols
+                    )
+                }
+            }
+            ShapeErrorKind::Reshape { len, cols } => writeln!(
+                f,
+                "Attempted reshaping {} elements into rows of {} cols.",
+                len, cols
+            ),
+            ShapeErrorKind::Len { len, expected_len } => writeln!(
+                f,
+                "The operation performed expected {} elements but the matrix has {}.",
+                expected_len, len
+            ),
+            ShapeErrorKind::InsertRow { row, rows } => writeln!(
                 f,
-                "The operation performed expected {} rows but the matrix has {}.",
-                self.expected_rows, self.rows
-            )
-        } else {
-            writeln!(
+                "Attempted inserting at row {}. The insert position should be in [0, {}]",
+                row, rows
+            ),
+            ShapeErrorKind::InsertCol { col, cols } => writeln!(
                 f,
-                "The operation performed expected {} cols but the matrix has {}.",
-                self.expected_cols, self.cols
-            )
+                "Attempted inserting at column {}. The insert position should be in [0, {}]",
+                col, cols
+            ),
+            ShapeErrorKind::MinRows { rows, min_rows } => writeln!(
+                f,
+                "The operation performed expected at least {} rows but the matrix has {}.",
+                min_rows, rows
+            ),
+            ShapeErrorKind::Window { window } => writeln!(
+                f,
+                "The operation performed expected a window of at least 1 element but the window has {}.",
+                window
+            ),
+            ShapeErrorKind::Block {
+                block_rows,
+                block_cols,
+            } => writeln!(
+                f,
+                "The operation performed expected a block of at least 1 element but the block has shape ({}, {}).",
+                block_rows, block_cols
+            ),
         }
     }
 }
 
+impl Error for ShapeError {}
+
 impl ShapeError {
     /// Create a new `ShapeError` given the shape of the matrix and the expected shape
     pub fn new(shape: (usize, usize), expected_shape: (usize, usize)) -> ShapeError {
         ShapeError {
-            rows: shape.0,
-            cols: shape.1,
-            expected_rows: expected_shape.0,
-            expected_cols: expected_shape.1,
+            kind: ShapeErrorKind::Shape {
+                rows: shape.0,
+                cols: shape.1,
+                expected_rows: expected_shape.0,
+                expected_cols: expected_shape.1,
+            },
         }
     }
 
     /// Create a new `ShapeError` given the rows of the matrix and the expected rows
     pub fn new_rows_error(rows: usize, expected_rows: usize) -> ShapeError {
-        ShapeError {
-            rows,
-            cols: 0,
-            expected_rows,
-            expected_cols: 0,
-        }
+        ShapeError::new((rows, 0), (expected_rows, 0))
     }
 
     /// Create a new `ShapeError` given the columns of the matrix and the expected columns
     pub fn new_cols_error(cols: usize, expected_cols: usize) -> ShapeError {
+        ShapeError::new((0, cols), (0, expected_cols))
+    }
+
+    /// Create a new `ShapeError` given the number of elements of the matrix and the number of columns it could not
+    /// be evenly split into
+    pub fn new_reshape_error(len: usize, cols: usize) -> ShapeError {
+        ShapeError {
+            kind: ShapeErrorKind::Reshape { len, cols },
+        }
+    }
+
+    /// Create a new `ShapeError` given the number of elements of the matrix and the expected number of elements
+    pub fn new_len_error(len: usize, expected_len: usize) -> ShapeError {
+        ShapeError {
+            kind: ShapeErrorKind::Len { len, expected_len },
+        }
+    }
+
+    /// Create a new `ShapeError` given the size of a window that has no elements
+    pub fn new_window_error(window: usize) -> ShapeError {
+        ShapeError {
+            kind: ShapeErrorKind::Window { window },
+        }
+    }
+
+    /// Create a new `ShapeError` given the shape of a block that has no elements
+    pub fn new_block_error(block: (usize, usize)) -> ShapeError {
+        ShapeError {
+            kind: ShapeErrorKind::Block {
+                block_rows: block.0,
+                block_cols: block.1,
+            },
+        }
+    }
+
+    /// Create a new `ShapeError` given the position a row was to be inserted at and the number of rows of the matrix
+    pub fn new_insert_row_error(row: usize, rows: usize) -> ShapeError {
+        ShapeError {
+            kind: ShapeErrorKind::InsertRow { row, rows },
+        }
+    }
+
+    /// Create a new `ShapeError` given the position a column was to be inserted at and the number of columns of the
+    /// matrix
+    pub fn new_insert_col_error(col: usize, cols: usize) -> ShapeError {
+        ShapeError {
+            kind: ShapeErrorKind::InsertCol { col, cols },
+        }
+    }
+
+    /// Create a new `ShapeError` given the rows of the matrix and the least number of rows the operation needs
+    pub fn new_min_rows_error(rows: usize, min_rows: usize) -> ShapeError {
         ShapeError {
-            rows: 0,
-            cols,
-            expected_rows: 0,
-            expected_cols,
+            kind: ShapeErrorKind::MinRows { rows, min_rows },
         }
     }
 }
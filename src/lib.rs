@@ -2,10 +2,16 @@
 
 //! A crate to work with dynamically sized matrices.
 
+/// Contains the column major ordered ColMajorMatrix
+mod col_major;
 /// Contains the errors associated with this crate
 pub mod errors;
+/// Contains the numeric traits used by this crate
+pub mod num;
 /// Contains the row major ordered DynamicMatrix
 mod row_major;
 
 // Re-exporting for ease-of-use
+pub use col_major::ColMajorMatrix;
+pub use row_major::iter;
 pub use row_major::DynamicMatrix;
@@ -0,0 +1,34 @@
+//! Numeric traits used by the constructors of numeric matrices
+
+/// Types that have an additive identity
+pub trait Zero {
+    /// Returns the additive identity, `0`
+    fn zero() -> Self;
+}
+
+/// Types that have a multiplicative identity
+pub trait One {
+    /// Returns the multiplicative identity, `1`
+    fn one() -> Self;
+}
+
+macro_rules! impl_zero_one {
+    ($zero:literal, $one:literal; $($t:ty),+) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+            }
+
+            impl One for $t {
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )+
+    };
+}
+
+impl_zero_one!(0, 1; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_zero_one!(0.0, 1.0; f32, f64);
@@ -1,9 +1,28 @@
 use std::{
+    collections::{TryReserveError, VecDeque},
+    fmt,
     ops::{Index, IndexMut},
     vec::Vec,
 };
 
-use crate::errors::{indexing_error::IndexingError, shape_error::ShapeError};
+use crate::{
+    errors::{indexing_error::IndexingError, shape_error::ShapeError},
+    num::{One, Zero},
+};
+
+/// Contains the block operations on a DynamicMatrix
+mod blocks;
+/// Contains the iterators over a DynamicMatrix
+pub mod iter;
+/// Contains the linear algebra routines on a DynamicMatrix of floats
+mod linalg;
+/// Contains the arithmetic operations on a DynamicMatrix
+mod ops;
+/// Contains the serde implementations for a DynamicMatrix
+#[cfg(feature = "serde")]
+mod serialize;
+
+use iter::{ColIter, ColIterMut, RowsIter, RowsIterMut};
 
 #[macro_export]
 /// A macro to construct a DynamicMatrix
@@ -73,8 +92,25 @@ macro_rules! dynamic_matrix {
 ///
 /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
 /// ```
+///
+/// Cloning a DynamicMatrix gives an independent copy with the same shape:
+/// ```
+/// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+/// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+/// let mut copy = mat.clone();
+///
+/// *copy.get_mut((0, 0)).unwrap() = 10;
+///
+/// assert_eq!(copy.as_slice(), &[10, 2, 3, 4, 5, 6]);
+/// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6]);
+///
+/// let mut empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(0);
+/// empty.push_row(vec![]).unwrap();
+/// assert_eq!(empty.clone().shape(), (1, 0));
+/// ```
 pub struct DynamicMatrix<T> {
     data: Vec<T>,
+    rows: usize,
     cols: usize,
 }
 
@@ -89,11 +125,10 @@ impl<T> DynamicMatrix<T> {
     /// assert_eq!(mat.as_slice(), [1, 2, 3, 4, 5, 6, 7, 8, 9]);
     /// ```
     pub fn new<const COLS: usize, const ROWS: usize>(data: [[T; COLS]; ROWS]) -> Self {
-        let cols = data[0].len();
-
         Self {
             data: data.into_iter().flatten().collect(),
-            cols,
+            rows: ROWS,
+            cols: COLS,
         }
     }
 
@@ -106,9 +141,19 @@ impl<T> DynamicMatrix<T> {
     /// assert_eq!(mat.rows(), 0);
     /// assert_eq!(mat.cols(), 3);
     /// ```
+    ///
+    /// A matrix with zero columns is allowed as well:
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(0);
+    ///
+    /// assert_eq!(mat.shape(), (0, 0));
+    /// assert!(mat.get((0, 0)).is_err());
+    /// ```
     pub fn new_with_cols(cols: usize) -> Self {
         Self {
             data: Vec::new(),
+            rows: 0,
             cols,
         }
     }
@@ -127,10 +172,192 @@ impl<T> DynamicMatrix<T> {
     pub fn with_capacity(shape: (usize, usize)) -> Self {
         Self {
             data: Vec::with_capacity(shape.0 * shape.1),
+            rows: 0,
+            cols: shape.1,
+        }
+    }
+
+    /// Constructs a new DynamicMatrix from an iterator of rows, taking the number of columns from the first row
+    ///
+    /// An empty iterator gives a matrix with no rows and no columns.
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat = DynamicMatrix::try_from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    ///
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// let empty = DynamicMatrix::<isize>::try_from_rows(Vec::new()).unwrap();
+    /// assert_eq!(empty.shape(), (0, 0));
+    /// ```
+    ///
+    /// Rows with unequal number of columns will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// DynamicMatrix::try_from_rows(vec![vec![1, 2, 3], vec![4, 5]]).unwrap();
+    /// ```
+    pub fn try_from_rows<I: IntoIterator<Item = Vec<T>>>(rows: I) -> Result<Self, ShapeError> {
+        let mut rows = rows.into_iter();
+
+        let first = match rows.next() {
+            Some(first) => first,
+            None => return Ok(Self::new_with_cols(0)),
+        };
+
+        let mut mat = Self::new_with_cols(first.len());
+        mat.push_row(first)?;
+        for row in rows {
+            mat.push_row(row)?;
+        }
+
+        Ok(mat)
+    }
+
+    /// Constructs a new DynamicMatrix of the provided shape with every element set to a clone of `value`
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat = DynamicMatrix::filled((2, 4), String::from("cell"));
+    ///
+    /// assert_eq!(mat.shape(), (2, 4));
+    /// assert!(mat.as_slice().iter().all(|e| e == "cell"));
+    ///
+    /// let empty = DynamicMatrix::filled((0, 4), String::new());
+    /// assert_eq!(empty.shape(), (0, 4));
+    /// ```
+    pub fn filled(shape: (usize, usize), value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            data: vec![value; shape.0 * shape.1],
+            rows: shape.0,
+            cols: shape.1,
+        }
+    }
+
+    /// Constructs a new DynamicMatrix of the provided shape by calling `f` with the row and column of every element in
+    /// row-major order
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let table = DynamicMatrix::from_fn((3, 4), |row, col| (row + 1) * (col + 1));
+    ///
+    /// assert_eq!(table.shape(), (3, 4));
+    /// assert_eq!(table.as_slice(), &[1, 2, 3, 4, 2, 4, 6, 8, 3, 6, 9, 12]);
+    ///
+    /// let empty = DynamicMatrix::from_fn((0, 4), |row, col| row + col);
+    /// assert_eq!(empty.shape(), (0, 4));
+    /// ```
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(shape: (usize, usize), mut f: F) -> Self {
+        let (rows, cols) = shape;
+        let mut data = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                data.push(f(row, col));
+            }
+        }
+
+        Self { data, rows, cols }
+    }
+
+    /// Constructs a new DynamicMatrix of the provided shape with every element set to a clone of `elem`
+    ///
+    /// This is the same as [`filled`](Self::filled).
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat = DynamicMatrix::from_element((2, 3), 7);
+    ///
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// assert_eq!(mat.as_slice(), &[7, 7, 7, 7, 7, 7]);
+    /// ```
+    pub fn from_element(shape: (usize, usize), elem: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::filled(shape, elem)
+    }
+
+    /// Constructs a new DynamicMatrix of the provided shape with every element set to zero
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat: DynamicMatrix<isize> = DynamicMatrix::zeros((2, 3));
+    ///
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// assert!(mat.as_slice().iter().all(|&e| e == 0));
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::zeros((0, 5));
+    /// assert_eq!(empty.shape(), (0, 5));
+    /// ```
+    pub fn zeros(shape: (usize, usize)) -> Self
+    where
+        T: Zero + Clone,
+    {
+        Self {
+            data: vec![T::zero(); shape.0 * shape.1],
+            rows: shape.0,
+            cols: shape.1,
+        }
+    }
+
+    /// Constructs a new DynamicMatrix of the provided shape with every element set to one
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat: DynamicMatrix<f64> = DynamicMatrix::ones((3, 2));
+    ///
+    /// assert_eq!(mat.shape(), (3, 2));
+    /// assert!(mat.as_slice().iter().all(|&e| e == 1.0));
+    /// ```
+    pub fn ones(shape: (usize, usize)) -> Self
+    where
+        T: One + Clone,
+    {
+        Self {
+            data: vec![T::one(); shape.0 * shape.1],
+            rows: shape.0,
             cols: shape.1,
         }
     }
 
+    /// Constructs a new `n x n` identity DynamicMatrix, with ones on the main diagonal and zeros elsewhere
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat: DynamicMatrix<isize> = DynamicMatrix::identity(4);
+    ///
+    /// assert_eq!(mat.shape(), (4, 4));
+    /// for row in 0..4 {
+    ///     for col in 0..4 {
+    ///         assert_eq!(mat[(row, col)], if row == col { 1 } else { 0 });
+    ///     }
+    /// }
+    ///
+    /// let empty: DynamicMatrix<f64> = DynamicMatrix::identity(0);
+    /// assert_eq!(empty.shape(), (0, 0));
+    /// ```
+    pub fn identity(n: usize) -> Self
+    where
+        T: Zero + One,
+    {
+        Self {
+            data: (0..n * n)
+                .map(|i| {
+                    if i % (n + 1) == 0 {
+                        T::one()
+                    } else {
+                        T::zero()
+                    }
+                })
+                .collect(),
+            rows: n,
+            cols: n,
+        }
+    }
+
     /// Returns the number of rows in the DynamicMatrix
     ///
     /// ```
@@ -139,8 +366,22 @@ impl<T> DynamicMatrix<T> {
     ///
     /// assert_eq!(mat.rows(), 3);
     /// ```
+    ///
+    /// The number of rows is tracked explicitly, so a matrix without any columns still has a well defined number of
+    /// rows:
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mut mat: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(0);
+    ///
+    /// assert_eq!(mat.rows(), 0);
+    ///
+    /// mat.push_row(vec![]).unwrap();
+    /// mat.push_row(vec![]).unwrap();
+    ///
+    /// assert_eq!(mat.rows(), 2);
+    /// ```
     pub fn rows(&self) -> usize {
-        self.data.len() / self.cols()
+        self.rows
     }
 
     /// Returns the number of columns in the DynamicMatrix
@@ -150,6 +391,10 @@ impl<T> DynamicMatrix<T> {
     /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
     ///
     /// assert_eq!(mat.cols(), 3);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::filled((4, 0), 0);
+    /// assert_eq!(empty.cols(), 0);
+    /// assert_eq!(empty.rows(), 4);
     /// ```
     pub fn cols(&self) -> usize {
         self.cols
@@ -162,11 +407,35 @@ impl<T> DynamicMatrix<T> {
     /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
     ///
     /// assert_eq!(mat.shape(), (3, 3));
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(0);
+    /// assert_eq!(empty.shape(), (0, 0));
     /// ```
     pub fn shape(&self) -> (usize, usize) {
         (self.rows(), self.cols())
     }
 
+    /// Returns a `ShapeError` if the DynamicMatrix is not square
+    fn ensure_square(&self) -> Result<(), ShapeError> {
+        if self.rows != self.cols {
+            Err(ShapeError::new(self.shape(), (self.rows, self.rows)))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns a `ShapeError` if the given shape does not hold exactly as many elements as the DynamicMatrix
+    fn ensure_len(&self, shape: (usize, usize)) -> Result<(), ShapeError> {
+        let len = self.data.len();
+        match shape.0.checked_mul(shape.1) {
+            Some(expected_len) if expected_len == len => Ok(()),
+            expected_len => Err(ShapeError::new_len_error(
+                len,
+                expected_len.unwrap_or(usize::MAX),
+            )),
+        }
+    }
+
     /// Returns the length of the underlying Vec
     ///
     /// ```
@@ -174,21 +443,157 @@ impl<T> DynamicMatrix<T> {
     /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
     ///
     /// assert_eq!(mat.len(), 9);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::filled((4, 0), 0);
+    /// assert_eq!(empty.len(), 0);
+    /// ```
     pub fn len(&self) -> usize {
-        self.data.capacity()
+        self.data.len()
+    }
+
+    /// Returns `true` if the DynamicMatrix contains no elements
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert!(mat.is_empty());
+    ///
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    /// assert!(!mat.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
     }
 
-    /// Returns the capacity of the underlying Vec
+    /// Returns the capacity of the underlying Vec, counted in elements rather than rows
     ///
     /// ```
     /// # use dynamic_matrix::DynamicMatrix;
     /// let mat: DynamicMatrix<isize> = DynamicMatrix::with_capacity((3, 3));
     ///
     /// assert_eq!(mat.capacity(), 9);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.capacity(), 0);
+    /// ```
     pub fn capacity(&self) -> usize {
         self.data.capacity()
     }
 
+    /// Returns the number of rows the DynamicMatrix can hold without reallocating
+    ///
+    /// A DynamicMatrix with no columns can hold any number of rows, so this returns `usize::MAX` for it.
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat: DynamicMatrix<isize> = DynamicMatrix::with_capacity((4, 3));
+    /// assert_eq!(mat.capacity_rows(), 4);
+    ///
+    /// let mat: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(0);
+    /// assert_eq!(mat.capacity_rows(), usize::MAX);
+    /// ```
+    pub fn capacity_rows(&self) -> usize {
+        self.data
+            .capacity()
+            .checked_div(self.cols)
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Reserves capacity for at least `additional_rows` more rows, that is `additional_rows * cols()` elements of
+    /// [`capacity`](Self::capacity)
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.reserve_rows(10);
+    ///
+    /// assert!(mat.capacity() >= 12 * 3);
+    /// assert!(mat.capacity_rows() >= 12);
+    /// ```
+    ///
+    /// Like `Vec::reserve` this panics if the new capacity overflows `isize::MAX` bytes.
+    pub fn reserve_rows(&mut self, additional_rows: usize) {
+        self.data.reserve(additional_rows.saturating_mul(self.cols));
+    }
+
+    /// Reserves capacity for exactly `additional_rows` more rows, that is `additional_rows * cols()` elements of
+    /// [`capacity`](Self::capacity)
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.reserve_rows_exact(2);
+    ///
+    /// assert!(mat.capacity_rows() >= 4);
+    /// ```
+    ///
+    /// Like `Vec::reserve_exact` this panics if the new capacity overflows `isize::MAX` bytes.
+    pub fn reserve_rows_exact(&mut self, additional_rows: usize) {
+        self.data
+            .reserve_exact(additional_rows.saturating_mul(self.cols));
+    }
+
+    /// Tries to reserve capacity for at least `additional_rows` more rows, returning an error instead of panicking
+    /// or aborting when the allocation cannot be made
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// assert!(mat.try_reserve_rows(10).is_ok());
+    /// assert!(mat.capacity_rows() >= 12);
+    ///
+    /// assert!(mat.try_reserve_rows(usize::MAX).is_err());
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// ```
+    pub fn try_reserve_rows(&mut self, additional_rows: usize) -> Result<(), TryReserveError> {
+        self.data
+            .try_reserve(additional_rows.saturating_mul(self.cols))
+    }
+
+    /// Shrinks the capacity of the DynamicMatrix as much as possible without touching its elements
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mut mat = DynamicMatrix::filled((100, 3), 1);
+    /// mat.truncate_rows(2);
+    /// assert!(mat.capacity() >= 300);
+    ///
+    /// mat.shrink_to_fit();
+    ///
+    /// assert!(mat.capacity() < 300);
+    /// assert!(mat.capacity() >= 6);
+    /// assert_eq!(mat.as_slice(), &[1; 6]);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+    }
+
+    /// Shrinks the capacity of the DynamicMatrix while keeping room for at least `min_capacity_rows` rows
+    ///
+    /// The capacity never drops below what the current rows need and is never grown.
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mut mat = DynamicMatrix::filled((100, 3), 1);
+    /// mat.truncate_rows(2);
+    ///
+    /// mat.shrink_to(10);
+    ///
+    /// assert!(mat.capacity() < 300);
+    /// assert!(mat.capacity_rows() >= 10);
+    /// assert_eq!(mat.as_slice(), &[1; 6]);
+    ///
+    /// mat.shrink_to(0);
+    /// assert!(mat.capacity_rows() >= 2);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity_rows: usize) {
+        self.data
+            .shrink_to(min_capacity_rows.saturating_mul(self.cols));
+    }
+
     /// Appends a new row to the DynamicMatrix
     ///
     /// ```
@@ -215,13 +620,133 @@ impl<T> DynamicMatrix<T> {
         if row.len() != self.cols() {
             Err(ShapeError::new_cols_error(self.cols(), row.len()))
         } else {
-            self.data.extend(row.into_iter());
+            self.data.extend(row);
+            self.rows += 1;
+
+            Ok(())
+        }
+    }
+
+    /// Inserts a new row into the DynamicMatrix at the given position, shifting the rows after it down
+    ///
+    /// The elements after the position are shifted only once. Inserting at `rows()` is the same as
+    /// [`push_row`](Self::push_row).
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![4, 5, 6];
+    ///
+    /// mat.insert_row(0, vec![1, 2, 3]).unwrap();
+    /// mat.insert_row(2, vec![10, 11, 12]).unwrap();
+    /// mat.insert_row(2, vec![7, 8, 9]).unwrap();
+    ///
+    /// assert_eq!(mat.shape(), (4, 3));
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+    /// ```
+    ///
+    /// Inserting past the last row or inserting a row with unequal number of columns will return a `ShapeError`:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// assert_eq!(
+    ///     mat.insert_row(3, vec![7, 8, 9]).unwrap_err().to_string(),
+    ///     "Attempted inserting at row 3. The insert position should be in [0, 2]\n"
+    /// );
+    /// assert!(mat.insert_row(1, vec![7, 8]).is_err());
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn insert_row(&mut self, at: usize, row: Vec<T>) -> Result<(), ShapeError> {
+        if at > self.rows {
+            Err(ShapeError::new_insert_row_error(at, self.rows))
+        } else if row.len() != self.cols {
+            Err(ShapeError::new_cols_error(self.cols, row.len()))
+        } else {
+            let start = at * self.cols;
+            self.data.splice(start..start, row);
+            self.rows += 1;
+
             Ok(())
         }
     }
 
+    /// Appends all the rows of an iterator to the DynamicMatrix
+    ///
+    /// Capacity for the rows is reserved up front using the iterator's size hint. If any of the rows has an unequal
+    /// number of columns, none of the rows are appended and the DynamicMatrix is left as it was.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3];
+    ///
+    /// mat.extend_rows(vec![vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+    ///
+    /// assert_eq!(mat.shape(), (3, 3));
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    ///
+    /// A row with unequal number of columns in the middle of the iterator returns a `ShapeError` and rolls back the
+    /// rows appended before it:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3];
+    ///
+    /// let result = mat.extend_rows(vec![vec![4, 5, 6], vec![7, 8], vec![10, 11, 12]]);
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(mat.shape(), (1, 3));
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3]);
+    /// ```
+    ///
+    /// An iterator reporting a lower bound too large to reserve for is still extended from:
+    /// ```
+    /// # use dynamic_matrix::dynamic_matrix;
+    /// struct Boastful(Option<Vec<i32>>);
+    ///
+    /// impl Iterator for Boastful {
+    ///     type Item = Vec<i32>;
+    ///
+    ///     fn next(&mut self) -> Option<Vec<i32>> {
+    ///         self.0.take()
+    ///     }
+    ///
+    ///     fn size_hint(&self) -> (usize, Option<usize>) {
+    ///         (usize::MAX / 2, None)
+    ///     }
+    /// }
+    ///
+    /// let mut mat = dynamic_matrix![1, 2, 3];
+    /// mat.extend_rows(Boastful(Some(vec![4, 5, 6]))).unwrap();
+    ///
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn extend_rows<I: IntoIterator<Item = Vec<T>>>(
+        &mut self,
+        rows: I,
+    ) -> Result<(), ShapeError> {
+        let rows = rows.into_iter();
+        // The lower bound is only a hint, so failing to reserve for it is not an error
+        let _ = self
+            .data
+            .try_reserve(rows.size_hint().0.saturating_mul(self.cols));
+
+        let (old_len, old_rows) = (self.data.len(), self.rows);
+        for row in rows {
+            if let Err(err) = self.push_row(row) {
+                self.data.truncate(old_len);
+                self.rows = old_rows;
+
+                return Err(err);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Appends a new columns to the DynamicMatrix
     ///
+    /// The elements are moved into a freshly allocated buffer in a single pass, so this is O(rows * cols).
+    ///
     /// ```
     /// # use dynamic_matrix::DynamicMatrix;
     /// let mut mat: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(2);
@@ -248,24 +773,584 @@ impl<T> DynamicMatrix<T> {
     /// // Trying to push a column with less elements than the number of rows
     /// mat.push_col(vec![3, 6]).unwrap();
     /// ```
+    ///
+    /// Columns can be pushed into a matrix that has rows but no columns:
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mut mat: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(0);
+    ///
+    /// mat.push_row(vec![]).unwrap();
+    /// mat.push_row(vec![]).unwrap();
+    /// mat.push_col(vec![1, 2]).unwrap();
+    ///
+    /// assert_eq!(mat.shape(), (2, 1));
+    /// assert_eq!(mat.as_slice(), &[1, 2]);
+    /// ```
+    ///
+    /// Pushing columns into large matrices stays cheap:
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let n = 1000;
+    /// let mut mat = DynamicMatrix::from_boxed_slice(vec![0usize; n * n].into_boxed_slice(), n);
+    ///
+    /// mat.push_col((0..n).collect()).unwrap();
+    ///
+    /// assert_eq!(mat.shape(), (n, n + 1));
+    /// for row in 0..n {
+    ///     assert_eq!(mat[(row, n)], row);
+    ///     assert_eq!(mat[(row, n - 1)], 0);
+    /// }
+    /// ```
     pub fn push_col(&mut self, col: Vec<T>) -> Result<(), ShapeError> {
-        if col.len() != self.rows() {
-            Err(ShapeError::new_rows_error(self.rows(), col.len()))
-        } else {
-            for (i, e) in col.into_iter().enumerate() {
-                self.data.insert(self.cols() + self.cols() * i + i, e);
-            }
-            self.cols += 1;
-
-            Ok(())
-        }
+        self.insert_col(self.cols, col)
     }
 
-    /// Gives a raw pointer to the underlying Vec's buffer
+    /// Inserts a new column into the DynamicMatrix at the given position, shifting the columns after it right
+    ///
+    /// Like [`push_col`](Self::push_col), the elements are moved into a freshly allocated buffer in a single pass, so
+    /// every element moves exactly once.
     ///
     /// ```
     /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
-    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    /// let mut mat = dynamic_matrix![1, 3; 4, 6; 7, 9];
+    ///
+    /// mat.insert_col(1, vec![2, 5, 8]).unwrap();
+    ///
+    /// assert_eq!(mat.shape(), (3, 3));
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    ///
+    /// Columns can be inserted at any position, and inserting at `cols()` is the same as
+    /// [`push_col`](Self::push_col):
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2; 3, 4];
+    ///
+    /// mat.insert_col(0, vec![0, 0]).unwrap();
+    /// assert_eq!(mat.as_slice(), &[0, 1, 2, 0, 3, 4]);
+    ///
+    /// mat.insert_col(2, vec![9, 9]).unwrap();
+    /// assert_eq!(mat.as_slice(), &[0, 1, 9, 2, 0, 3, 9, 4]);
+    ///
+    /// let mut pushed = mat.clone();
+    /// mat.insert_col(4, vec![5, 6]).unwrap();
+    /// pushed.push_col(vec![5, 6]).unwrap();
+    /// assert_eq!(mat.as_slice(), pushed.as_slice());
+    /// assert_eq!(mat.shape(), (2, 5));
+    /// ```
+    ///
+    /// Inserting past the last column or inserting a column with unequal number of rows will return a `ShapeError`:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2; 3, 4];
+    ///
+    /// assert_eq!(
+    ///     mat.insert_col(3, vec![5, 6]).unwrap_err().to_string(),
+    ///     "Attempted inserting at column 3. The insert position should be in [0, 2]\n"
+    /// );
+    /// assert!(mat.insert_col(0, vec![5, 6, 7]).is_err());
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4]);
+    /// ```
+    pub fn insert_col(&mut self, at: usize, col: Vec<T>) -> Result<(), ShapeError> {
+        if at > self.cols {
+            Err(ShapeError::new_insert_col_error(at, self.cols))
+        } else if col.len() != self.rows {
+            Err(ShapeError::new_rows_error(self.rows, col.len()))
+        } else {
+            let mut data = Vec::with_capacity(self.rows * (self.cols + 1));
+            let mut old = std::mem::take(&mut self.data).into_iter();
+            for e in col {
+                data.extend(old.by_ref().take(at));
+                data.push(e);
+                data.extend(old.by_ref().take(self.cols - at));
+            }
+
+            self.data = data;
+            self.cols += 1;
+
+            Ok(())
+        }
+    }
+
+    /// Removes the given row from the DynamicMatrix and returns it, moving the last row into its place
+    ///
+    /// This does not preserve the order of the rows, but only moves a single row, so it is O(cols).
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2; 3, 4; 5, 6; 7, 8];
+    ///
+    /// assert_eq!(mat.swap_remove_row(0).unwrap(), vec![1, 2]);
+    /// assert_eq!(mat.as_slice(), &[7, 8, 3, 4, 5, 6]);
+    ///
+    /// // Removing the last row just pops it
+    /// assert_eq!(mat.swap_remove_row(2).unwrap(), vec![5, 6]);
+    /// assert_eq!(mat.as_slice(), &[7, 8, 3, 4]);
+    /// ```
+    ///
+    /// Removing several rows from a large matrix keeps every other row:
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mut mat: DynamicMatrix<usize> = (0..1000).map(|i| vec![i, i + 1, i + 2]).collect();
+    ///
+    /// for i in [10, 500, 0, 996] {
+    ///     let row = mat.swap_remove_row(i).unwrap();
+    ///     assert_eq!(row.len(), 3);
+    /// }
+    ///
+    /// let mut firsts: Vec<usize> = mat.rows_iter().map(|row| row[0]).collect();
+    /// firsts.sort();
+    ///
+    /// let expected: Vec<usize> = (0..1000).filter(|i| ![10, 500, 0, 996].contains(i)).collect();
+    /// assert_eq!(firsts, expected);
+    /// assert!(mat.rows_iter().all(|row| row[1] == row[0] + 1 && row[2] == row[0] + 2));
+    /// ```
+    ///
+    /// Removing a row outside bounds will return an `IndexingError`.
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2; 3, 4];
+    ///
+    /// mat.swap_remove_row(2).unwrap();
+    /// ```
+    pub fn swap_remove_row(&mut self, row: usize) -> Result<Vec<T>, IndexingError> {
+        if row >= self.rows {
+            return Err(IndexingError::new_row_error(row, self.rows));
+        }
+
+        self.swap_rows(row, self.rows - 1)?;
+        self.remove_row(self.rows - 1)
+    }
+
+    /// Removes the last row from the DynamicMatrix and returns it, or `None` if there are no rows
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// let original = mat.clone();
+    ///
+    /// let row = mat.pop_row().unwrap();
+    /// assert_eq!(row, vec![4, 5, 6]);
+    /// assert_eq!(mat.shape(), (1, 3));
+    ///
+    /// // Pushing the row back reproduces the original matrix
+    /// mat.push_row(row).unwrap();
+    /// assert_eq!(mat.as_slice(), original.as_slice());
+    ///
+    /// let mut empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.pop_row(), None);
+    /// ```
+    pub fn pop_row(&mut self) -> Option<Vec<T>> {
+        let last = self.rows.checked_sub(1)?;
+
+        self.remove_row(last).ok()
+    }
+
+    /// Removes the last column from the DynamicMatrix and returns its elements from top to bottom, or `None` if there
+    /// are no columns
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// let original = mat.clone();
+    ///
+    /// let col = mat.pop_col().unwrap();
+    /// assert_eq!(col, vec![3, 6]);
+    /// assert_eq!(mat.shape(), (2, 2));
+    ///
+    /// // Pushing the column back reproduces the original matrix
+    /// mat.push_col(col).unwrap();
+    /// assert_eq!(mat.as_slice(), original.as_slice());
+    ///
+    /// // Popping every column keeps the number of rows
+    /// while mat.pop_col().is_some() {}
+    /// assert_eq!(mat.shape(), (2, 0));
+    /// assert_eq!(mat.pop_col(), None);
+    /// ```
+    pub fn pop_col(&mut self) -> Option<Vec<T>> {
+        let last = self.cols.checked_sub(1)?;
+
+        self.remove_col(last).ok()
+    }
+
+    /// Removes the given row from the DynamicMatrix and returns it, shifting the rows after it up
+    ///
+    /// The row is drained out of the underlying Vec in one go, so this is O(elements after the row).
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// assert_eq!(mat.remove_row(0).unwrap(), vec![1, 2, 3]);
+    /// assert_eq!(mat.as_slice(), &[4, 5, 6]);
+    ///
+    /// // Removing the last remaining row keeps the number of columns
+    /// assert_eq!(mat.remove_row(0).unwrap(), vec![4, 5, 6]);
+    /// assert_eq!(mat.shape(), (0, 3));
+    /// ```
+    ///
+    /// Rows can be removed from any position:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2; 3, 4; 5, 6; 7, 8; 9, 10];
+    ///
+    /// assert_eq!(mat.remove_row(0).unwrap(), vec![1, 2]);
+    /// assert_eq!(mat.as_slice(), &[3, 4, 5, 6, 7, 8, 9, 10]);
+    ///
+    /// assert_eq!(mat.remove_row(1).unwrap(), vec![5, 6]);
+    /// assert_eq!(mat.as_slice(), &[3, 4, 7, 8, 9, 10]);
+    ///
+    /// assert_eq!(mat.remove_row(2).unwrap(), vec![9, 10]);
+    /// assert_eq!(mat.as_slice(), &[3, 4, 7, 8]);
+    /// assert_eq!(mat.shape(), (2, 2));
+    /// ```
+    ///
+    /// Removing a row outside bounds will return an `IndexingError`.
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.remove_row(2).unwrap();
+    /// ```
+    pub fn remove_row(&mut self, row: usize) -> Result<Vec<T>, IndexingError> {
+        if row >= self.rows {
+            return Err(IndexingError::new_row_error(row, self.rows));
+        }
+
+        let removed = self
+            .data
+            .drain(row * self.cols..(row + 1) * self.cols)
+            .collect();
+        self.rows -= 1;
+
+        Ok(removed)
+    }
+
+    /// Removes the given column from the DynamicMatrix and returns its elements from top to bottom, shifting the
+    /// columns after it left
+    ///
+    /// The remaining elements are compacted in place in a single forward pass over the underlying Vec, so this is
+    /// O(rows * cols) and keeps the capacity of the DynamicMatrix.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![
+    ///     1, 2, 3, 4;
+    ///     5, 6, 7, 8;
+    ///     9, 10, 11, 12;
+    /// ];
+    ///
+    /// assert_eq!(mat.remove_col(1).unwrap(), vec![2, 6, 10]);
+    ///
+    /// assert_eq!(mat.shape(), (3, 3));
+    /// assert_eq!(mat.as_slice(), &[1, 3, 4, 5, 7, 8, 9, 11, 12]);
+    /// ```
+    ///
+    /// Columns can be removed from any position, including from a matrix without rows:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// for col in 0..3 {
+    ///     let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    ///     assert_eq!(mat.remove_col(col).unwrap(), vec![col + 1, col + 4]);
+    ///     assert_eq!(mat.shape(), (2, 2));
+    ///     for row in 0..2 {
+    ///         let expected: Vec<usize> = (1..=3).filter(|&c| c != col + 1).map(|c| 3 * row + c).collect();
+    ///         assert_eq!(mat.row(row).unwrap(), expected.as_slice());
+    ///     }
+    /// }
+    ///
+    /// let mut empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.remove_col(2).unwrap(), vec![]);
+    /// assert_eq!(empty.shape(), (0, 2));
+    ///
+    /// for (rows, cols) in [(7, 2), (5, 4), (2, 9)] {
+    ///     for col in 0..cols {
+    ///         let mut mat = DynamicMatrix::from_fn((rows, cols), |r, c| r * cols + c);
+    ///         let capacity = mat.capacity();
+    ///
+    ///         let removed = mat.remove_col(col).unwrap();
+    ///         assert_eq!(removed, (0..rows).map(|r| r * cols + col).collect::<Vec<_>>());
+    ///
+    ///         let expected: Vec<usize> = (0..rows * cols).filter(|i| i % cols != col).collect();
+    ///         assert_eq!(mat.as_slice(), expected.as_slice());
+    ///         assert_eq!(mat.capacity(), capacity);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Removing a column outside bounds will return an `IndexingError`.
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.remove_col(3).unwrap();
+    /// ```
+    pub fn remove_col(&mut self, col: usize) -> Result<Vec<T>, IndexingError> {
+        if col >= self.cols {
+            return Err(IndexingError::new_col_error(col, self.cols));
+        }
+
+        // Compact the kept elements forward in place. The removed elements gather in a run right after the kept
+        // ones, and moving a kept element in front of that run rotates it by one, so `order` tracks which row each
+        // element of the run came from.
+        let cols = self.cols;
+        let mut order = VecDeque::with_capacity(self.rows);
+        let mut write = 0;
+        for i in 0..self.data.len() {
+            if i % cols == col {
+                order.push_back(i / cols);
+            } else {
+                if !order.is_empty() {
+                    self.data.swap(write, i);
+                    order.rotate_left(1);
+                }
+                write += 1;
+            }
+        }
+
+        let mut removed = self.data.split_off(write);
+        let mut order = Vec::from(order);
+        for i in 0..removed.len() {
+            while order[i] != i {
+                let row = order[i];
+                removed.swap(i, row);
+                order.swap(i, row);
+            }
+        }
+        self.cols -= 1;
+
+        Ok(removed)
+    }
+
+    /// Shortens the DynamicMatrix to its first `rows` rows, doing nothing if it has no more than `rows` rows
+    ///
+    /// Like `Vec::truncate`, this does not change the capacity.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2; 3, 4; 5, 6];
+    /// let capacity = mat.capacity();
+    ///
+    /// mat.truncate_rows(1);
+    ///
+    /// assert_eq!(mat.shape(), (1, 2));
+    /// assert_eq!(mat.as_slice(), &[1, 2]);
+    /// assert_eq!(mat.capacity(), capacity);
+    ///
+    /// mat.truncate_rows(5);
+    /// assert_eq!(mat.shape(), (1, 2));
+    /// ```
+    pub fn truncate_rows(&mut self, rows: usize) {
+        if rows < self.rows {
+            self.data.truncate(rows * self.cols);
+            self.rows = rows;
+        }
+    }
+
+    /// Removes all the rows of the DynamicMatrix, keeping the number of columns and the allocated capacity
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// let capacity = mat.capacity();
+    ///
+    /// mat.clear();
+    ///
+    /// assert!(mat.is_empty());
+    /// assert_eq!(mat.shape(), (0, 3));
+    /// assert_eq!(mat.capacity(), capacity);
+    ///
+    /// mat.push_row(vec![7, 8, 9]).unwrap();
+    /// assert_eq!(mat.as_slice(), &[7, 8, 9]);
+    /// ```
+    pub fn clear(&mut self) {
+        self.data.clear();
+        self.rows = 0;
+    }
+
+    /// Resizes the DynamicMatrix to have `rows` rows, either truncating it or appending rows filled with clones of
+    /// `fill`
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.resize_rows(4, 0);
+    /// assert_eq!(mat.shape(), (4, 3));
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6, 0, 0, 0, 0, 0, 0]);
+    ///
+    /// mat.resize_rows(1, 0);
+    /// assert_eq!(mat.shape(), (1, 3));
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn resize_rows(&mut self, rows: usize, fill: T)
+    where
+        T: Clone,
+    {
+        self.data.resize(rows * self.cols, fill);
+        self.rows = rows;
+    }
+
+    /// Resizes the DynamicMatrix to the given shape, keeping the elements in the overlapping top-left region and
+    /// filling every new cell with clones of `fill`
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.resize((3, 2), 0);
+    /// assert_eq!(mat.shape(), (3, 2));
+    /// assert_eq!(mat.as_slice(), &[1, 2, 4, 5, 0, 0]);
+    ///
+    /// mat.resize((2, 4), 9);
+    /// assert_eq!(mat.shape(), (2, 4));
+    /// assert_eq!(mat.as_slice(), &[1, 2, 9, 9, 4, 5, 9, 9]);
+    ///
+    /// mat.resize((0, 3), 0);
+    /// assert_eq!(mat.shape(), (0, 3));
+    /// assert!(mat.is_empty());
+    ///
+    /// mat.resize((2, 2), 7);
+    /// assert_eq!(mat.shape(), (2, 2));
+    /// assert_eq!(mat.as_slice(), &[7, 7, 7, 7]);
+    /// ```
+    pub fn resize(&mut self, shape: (usize, usize), fill: T)
+    where
+        T: Clone,
+    {
+        let (rows, cols) = shape;
+        if cols == self.cols {
+            self.resize_rows(rows, fill);
+            return;
+        }
+
+        let kept_rows = rows.min(self.rows);
+        let kept_cols = cols.min(self.cols);
+        let dropped_cols = self.cols - kept_cols;
+
+        let mut data = Vec::with_capacity(rows * cols);
+        let mut old = std::mem::take(&mut self.data).into_iter();
+        for _ in 0..kept_rows {
+            data.extend(old.by_ref().take(kept_cols));
+            data.resize(data.len() + cols - kept_cols, fill.clone());
+            if dropped_cols > 0 {
+                old.nth(dropped_cols - 1);
+            }
+        }
+        data.resize(rows * cols, fill);
+
+        self.data = data;
+        self.rows = rows;
+        self.cols = cols;
+    }
+
+    /// Reinterprets the elements of the DynamicMatrix in row-major order under the given shape, without copying them
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3, 4, 5, 6; 7, 8, 9, 10, 11, 12];
+    /// let ptr = mat.as_ptr();
+    ///
+    /// let mat = mat.reshape((3, 4)).unwrap();
+    ///
+    /// assert_eq!(mat.shape(), (3, 4));
+    /// assert_eq!(mat.row(1).unwrap(), &[5, 6, 7, 8]);
+    /// assert_eq!(mat.as_ptr(), ptr);
+    /// ```
+    ///
+    /// Reshaping into a shape holding a different number of elements will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3, 4, 5, 6; 7, 8, 9, 10, 11, 12];
+    ///
+    /// mat.reshape((5, 2)).unwrap();
+    /// ```
+    pub fn reshape(mut self, shape: (usize, usize)) -> Result<DynamicMatrix<T>, ShapeError> {
+        self.ensure_len(shape)?;
+        self.rows = shape.0;
+        self.cols = shape.1;
+
+        Ok(self)
+    }
+
+    /// Returns a copy of the DynamicMatrix reshaped into the given shape, see [`reshape`](Self::reshape)
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3, 4, 5, 6; 7, 8, 9, 10, 11, 12];
+    ///
+    /// let reshaped = mat.reshaped((4, 3)).unwrap();
+    ///
+    /// assert_eq!(reshaped.shape(), (4, 3));
+    /// assert_eq!(reshaped.as_slice(), mat.as_slice());
+    /// assert_eq!(mat.shape(), (2, 6));
+    ///
+    /// assert_eq!(
+    ///     mat.reshaped((5, 2)).unwrap_err().to_string(),
+    ///     "The operation performed expected 10 elements but the matrix has 12.\n"
+    /// );
+    /// ```
+    pub fn reshaped(&self, shape: (usize, usize)) -> Result<DynamicMatrix<T>, ShapeError>
+    where
+        T: Clone,
+    {
+        self.ensure_len(shape)?;
+
+        Ok(DynamicMatrix {
+            data: self.data.clone(),
+            rows: shape.0,
+            cols: shape.1,
+        })
+    }
+
+    /// Reinterprets the elements of the DynamicMatrix in row-major order as rows of `cols` columns, inferring the
+    /// number of rows from the number of elements
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3, 4, 5, 6; 7, 8, 9, 10, 11, 12];
+    ///
+    /// mat.reshape_rows(4).unwrap();
+    ///
+    /// assert_eq!(mat.shape(), (3, 4));
+    /// assert_eq!(mat.row(1).unwrap(), &[5, 6, 7, 8]);
+    ///
+    /// let mut no_cols: DynamicMatrix<isize> = DynamicMatrix::filled((4, 0), 0);
+    /// no_cols.reshape_rows(0).unwrap();
+    /// assert_eq!(no_cols.shape(), (4, 0));
+    /// ```
+    ///
+    /// Reshaping into a number of columns that does not evenly divide the number of elements will return a
+    /// `ShapeError` and leave the DynamicMatrix unchanged:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3, 4, 5, 6; 7, 8, 9, 10, 11, 12];
+    ///
+    /// mat.reshape_rows(5).unwrap();
+    /// ```
+    pub fn reshape_rows(&mut self, cols: usize) -> Result<(), ShapeError> {
+        let len = self.data.len();
+        let rows = match len.checked_div(cols) {
+            Some(rows) if rows * cols == len => rows,
+            // With no columns the number of rows can't be inferred, so a DynamicMatrix that already has no columns
+            // keeps its rows
+            None if len == 0 && self.cols == 0 => self.rows,
+            None if len == 0 => 0,
+            _ => return Err(ShapeError::new_reshape_error(len, cols)),
+        };
+
+        self.rows = rows;
+        self.cols = cols;
+
+        Ok(())
+    }
+
+    /// Gives a raw pointer to the underlying Vec's buffer
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
     ///
     /// let mat_ptr = mat.as_ptr();
     /// for i in 0..(mat.rows() * mat.cols()) {
@@ -302,6 +1387,9 @@ impl<T> DynamicMatrix<T> {
     /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
     ///
     /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert!(empty.as_slice().is_empty());
     /// ```
     pub fn as_slice(&self) -> &[T] {
         self.data.as_slice()
@@ -319,26 +1407,71 @@ impl<T> DynamicMatrix<T> {
     /// mat_slice[2] = 12;
     ///
     /// assert_eq!(mat.as_slice(), &[10, 11, 12, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// let mut empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert!(empty.as_mut_slice().is_empty());
     /// ```
     pub fn as_mut_slice(&mut self) -> &mut [T] {
         self.data.as_mut_slice()
     }
 
     /// Decomposes the DynamicMatrix into the raw compoenents of it's underlying Vec
-    /// The returned tuple has three elements: (raw parts of the underlying vector, number of columns)
-    // TODO tests
-    #[cfg(vec_into_raw_parts)]
+    /// The returned tuple has two elements: (raw parts of the underlying vector, number of columns)
+    ///
+    /// Only the number of columns is kept alongside the raw parts, so a DynamicMatrix with rows but no columns comes
+    /// back from [`from_raw_parts`](Self::from_raw_parts) with no rows.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let (parts, cols) = mat.into_raw_parts();
+    /// let mat = unsafe { DynamicMatrix::from_raw_parts(parts, cols) };
+    ///
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// let (parts, cols) = empty.into_raw_parts();
+    /// assert_eq!(unsafe { DynamicMatrix::from_raw_parts(parts, cols) }.shape(), (0, 3));
+    ///
+    /// let no_cols: DynamicMatrix<isize> = DynamicMatrix::filled((4, 0), 0);
+    /// let (parts, cols) = no_cols.into_raw_parts();
+    /// assert_eq!(unsafe { DynamicMatrix::from_raw_parts(parts, cols) }.shape(), (0, 0));
+    /// ```
+    #[doc(hidden)]
     pub fn into_raw_parts(self) -> ((*mut T, usize, usize), usize) {
         let cols = self.cols();
+        let mut data = std::mem::ManuallyDrop::new(self.data);
 
-        (self.data.into_raw_parts(), cols)
+        ((data.as_mut_ptr(), data.len(), data.capacity()), cols)
     }
 
     /// Creates a DynamicMatrix from it's underlying raw components
-    // TODO tests
+    ///
+    /// The number of rows is inferred from the length, so with `cols` set to `0` the DynamicMatrix has no rows.
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mut data = std::mem::ManuallyDrop::new(vec![1, 2, 3, 4, 5, 6]);
+    /// let parts = (data.as_mut_ptr(), data.len(), data.capacity());
+    ///
+    /// let mat = unsafe { DynamicMatrix::from_raw_parts(parts, 2) };
+    ///
+    /// assert_eq!(mat.shape(), (3, 2));
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The raw parts must uphold the same invariants as `Vec::from_raw_parts` and the length must be a multiple of
+    /// `cols`.
     pub unsafe fn from_raw_parts(vec_parts: (*mut T, usize, usize), cols: usize) -> Self {
+        let rows = vec_parts.1.checked_div(cols).unwrap_or(0);
+
         Self {
             data: Vec::from_raw_parts(vec_parts.0, vec_parts.1, vec_parts.2),
+            rows,
             cols,
         }
     }
@@ -353,6 +1486,11 @@ impl<T> DynamicMatrix<T> {
     ///
     /// assert_eq!(cols, 3);
     /// assert_eq!(slice.as_ref(), [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// let (slice, cols) = empty.into_boxed_slice();
+    /// assert!(slice.is_empty());
+    /// assert_eq!(cols, 3);
     /// ```
     pub fn into_boxed_slice(self) -> (Box<[T]>, usize) {
         let cols = self.cols();
@@ -362,6 +1500,9 @@ impl<T> DynamicMatrix<T> {
 
     /// Creates a DynamicMatrix from a Boxed slice
     ///
+    /// Panics if the length of the slice is not a multiple of `cols`, see
+    /// [`try_from_boxed_slice`](Self::try_from_boxed_slice) for a non-panicking variant.
+    ///
     /// ```
     /// # use dynamic_matrix::DynamicMatrix;
     /// let boxed_slice = Box::new([1, 2, 3, 4, 5, 6, 7, 8, 9]);
@@ -371,23 +1512,100 @@ impl<T> DynamicMatrix<T> {
     /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
     /// ```
     pub fn from_boxed_slice(boxed_slice: Box<[T]>, cols: usize) -> Self {
-        Self {
-            data: boxed_slice.into_vec(),
-            cols,
-        }
+        Self::try_from_boxed_slice(boxed_slice, cols).unwrap()
     }
 
-    /// Returns a `Result` containing a shared reference to the value at the given index
+    /// Creates a DynamicMatrix from a Boxed slice, checking that its length is a multiple of `cols`
     ///
     /// ```
-    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
-    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat = DynamicMatrix::try_from_boxed_slice(Box::new([1, 2, 3, 4, 5, 6]), 2).unwrap();
     ///
-    /// for row in 0..mat.rows() {
-    ///     for col in 0..mat.cols() {
-    ///         assert_eq!(*mat.get((row, col)).unwrap(), 3 * row + col + 1);
-    ///     }
+    /// assert_eq!(mat.shape(), (3, 2));
+    /// ```
+    ///
+    /// A length that is not a multiple of `cols` will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// DynamicMatrix::try_from_boxed_slice(Box::new([1, 2, 3, 4, 5]), 2).unwrap();
+    /// ```
+    pub fn try_from_boxed_slice(boxed_slice: Box<[T]>, cols: usize) -> Result<Self, ShapeError> {
+        Self::from_vec(boxed_slice.into_vec(), cols)
+    }
+
+    /// Decomposes the DynamicMatrix into it's underlying Vec and the number of columns
+    ///
+    /// The number of rows is not returned, so a DynamicMatrix with rows but no columns can't be told apart from one
+    /// without rows. Use [`shape`](Self::shape) beforehand if it matters.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let (vec, cols) = mat.into_vec();
+    ///
+    /// assert_eq!(cols, 3);
+    /// assert_eq!(vec, vec![1, 2, 3, 4, 5, 6]);
+    ///
+    /// let no_cols: DynamicMatrix<isize> = DynamicMatrix::filled((4, 0), 0);
+    /// let (vec, cols) = no_cols.into_vec();
+    /// assert_eq!(DynamicMatrix::from_vec(vec, cols).unwrap().shape(), (0, 0));
+    /// ```
+    pub fn into_vec(self) -> (Vec<T>, usize) {
+        (self.data, self.cols)
+    }
+
+    /// Creates a DynamicMatrix from a Vec of elements in row-major order, inferring the number of rows from its
+    /// length
+    ///
+    /// With `cols` set to `0` the number of rows can't be inferred, so an empty Vec gives a DynamicMatrix with no rows
+    /// and no columns.
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat = DynamicMatrix::from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+    ///
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// let empty = DynamicMatrix::<isize>::from_vec(Vec::new(), 0).unwrap();
+    /// assert_eq!(empty.shape(), (0, 0));
+    /// ```
+    ///
+    /// A length that is not a multiple of `cols`, or any elements with `cols` set to `0`, will return a `ShapeError`:
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// assert_eq!(
+    ///     DynamicMatrix::from_vec(vec![1, 2, 3, 4, 5], 2).unwrap_err().to_string(),
+    ///     "Attempted reshaping 5 elements into rows of 2 cols.\n"
+    /// );
+    /// assert!(DynamicMatrix::from_vec(vec![1, 2, 3], 0).is_err());
+    /// ```
+    pub fn from_vec(data: Vec<T>, cols: usize) -> Result<Self, ShapeError> {
+        let mut mat = Self {
+            data,
+            rows: 0,
+            cols: 0,
+        };
+        mat.reshape_rows(cols)?;
+
+        Ok(mat)
+    }
+
+    /// Returns a `Result` containing a shared reference to the value at the given index
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// for row in 0..mat.rows() {
+    ///     for col in 0..mat.cols() {
+    ///         assert_eq!(*mat.get((row, col)).unwrap(), 3 * row + col + 1);
+    ///     }
     /// }
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert!(empty.get((0, 0)).is_err());
     /// ```
     ///
     /// Indexing outside bounds will return an `IndexingError`.
@@ -422,6 +1640,9 @@ impl<T> DynamicMatrix<T> {
     /// }
     ///
     /// assert_eq!(mat.as_slice(), &[10, 11, 12, 13, 14, 15, 16, 17, 18]);
+    ///
+    /// let mut empty: DynamicMatrix<isize> = DynamicMatrix::filled((3, 0), 0);
+    /// assert!(empty.get_mut((0, 0)).is_err());
     /// ```
     ///
     /// Indexing outside bounds will return an `IndexingError`.
@@ -444,44 +1665,1414 @@ impl<T> DynamicMatrix<T> {
             Err(IndexingError::new(index, self.shape()))
         }
     }
-}
 
-impl<T> Index<(usize, usize)> for DynamicMatrix<T> {
-    type Output = T;
+    /// Swaps the elements at the two given indices
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.swap((0, 0), (1, 2)).unwrap();
+    ///
+    /// assert_eq!(mat.as_slice(), &[6, 2, 3, 4, 5, 1]);
+    /// ```
+    ///
+    /// Indexing outside bounds will return an `IndexingError` for the offending index, leaving the DynamicMatrix
+    /// unchanged:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let err = mat.swap((0, 0), (1, 3)).unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "Attempted indexing column 3. The columns index should be in [0, 3)\n"
+    /// );
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) -> Result<(), IndexingError> {
+        for index in [a, b] {
+            if index.0 >= self.rows || index.1 >= self.cols {
+                return Err(IndexingError::new(index, self.shape()));
+            }
+        }
 
-    /// Returns a shared reference to the value at the given index
+        self.data.swap(a.0 * self.cols + a.1, b.0 * self.cols + b.1);
+
+        Ok(())
+    }
+
+    /// Swaps the two given rows
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2; 3, 4; 5, 6];
+    ///
+    /// mat.swap_rows(0, 2).unwrap();
+    /// assert_eq!(mat.as_slice(), &[5, 6, 3, 4, 1, 2]);
+    ///
+    /// // Swapping a row with itself does nothing
+    /// mat.swap_rows(1, 1).unwrap();
+    /// assert_eq!(mat.as_slice(), &[5, 6, 3, 4, 1, 2]);
+    /// ```
+    ///
+    /// Swapping a row outside bounds will return an `IndexingError`.
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2; 3, 4; 5, 6];
+    ///
+    /// mat.swap_rows(0, 3).unwrap();
+    /// ```
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> Result<(), IndexingError> {
+        for row in [a, b] {
+            if row >= self.rows {
+                return Err(IndexingError::new_row_error(row, self.rows));
+            }
+        }
+
+        if a != b {
+            let (low, high) = (a.min(b), a.max(b));
+            let (head, tail) = self.data.split_at_mut(high * self.cols);
+            head[low * self.cols..(low + 1) * self.cols].swap_with_slice(&mut tail[..self.cols]);
+        }
+
+        Ok(())
+    }
+
+    /// Swaps the two given columns
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.swap_cols(0, 2).unwrap();
+    /// assert_eq!(mat.as_slice(), &[3, 2, 1, 6, 5, 4]);
+    ///
+    /// // Swapping a column with itself does nothing
+    /// mat.swap_cols(1, 1).unwrap();
+    /// assert_eq!(mat.as_slice(), &[3, 2, 1, 6, 5, 4]);
+    /// ```
+    ///
+    /// Swapping a column outside bounds will return an `IndexingError`.
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.swap_cols(3, 0).unwrap();
+    /// ```
+    pub fn swap_cols(&mut self, a: usize, b: usize) -> Result<(), IndexingError> {
+        for col in [a, b] {
+            if col >= self.cols {
+                return Err(IndexingError::new_col_error(col, self.cols));
+            }
+        }
+
+        for row in self.rows_iter_mut() {
+            row.swap(a, b);
+        }
+
+        Ok(())
+    }
+
+    /// Reverses the order of the rows in place, mirroring the DynamicMatrix vertically
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2; 3, 4; 5, 6];
+    /// mat.flip_rows();
+    /// assert_eq!(mat.as_slice(), &[5, 6, 3, 4, 1, 2]);
+    ///
+    /// let mut mat = dynamic_matrix![1, 2; 3, 4; 5, 6; 7, 8];
+    /// mat.flip_rows();
+    /// assert_eq!(mat.as_slice(), &[7, 8, 5, 6, 3, 4, 1, 2]);
+    /// ```
+    pub fn flip_rows(&mut self) {
+        if self.cols == 0 {
+            return;
+        }
+
+        let (head, tail) = self.data.split_at_mut(self.rows / 2 * self.cols);
+        for (top, bottom) in head
+            .chunks_exact_mut(self.cols)
+            .zip(tail.rchunks_exact_mut(self.cols))
+        {
+            top.swap_with_slice(bottom);
+        }
+    }
+
+    /// Reverses the order of the columns in place, mirroring the DynamicMatrix horizontally
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// mat.flip_cols();
+    /// assert_eq!(mat.as_slice(), &[3, 2, 1, 6, 5, 4]);
+    ///
+    /// let mut mat = dynamic_matrix![1, 2, 3, 4; 5, 6, 7, 8];
+    /// mat.flip_cols();
+    /// assert_eq!(mat.as_slice(), &[4, 3, 2, 1, 8, 7, 6, 5]);
+    /// ```
+    pub fn flip_cols(&mut self) {
+        for row in self.rows_iter_mut() {
+            row.reverse();
+        }
+    }
+
+    /// Returns the DynamicMatrix rotated a quarter turn clockwise
+    ///
+    /// An `r`×`c` DynamicMatrix becomes `c`×`r` with `rotated[(j, r - 1 - i)] == mat[(i, j)]`.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let rotated = mat.rotate_cw();
+    /// assert_eq!(rotated.shape(), (3, 2));
+    /// assert_eq!(rotated.as_slice(), &[4, 1, 5, 2, 6, 3]);
+    ///
+    /// let full_turn = rotated.rotate_cw().rotate_cw().rotate_cw();
+    /// assert_eq!(full_turn.shape(), mat.shape());
+    /// assert_eq!(full_turn.as_slice(), mat.as_slice());
+    /// ```
+    pub fn rotate_cw(&self) -> DynamicMatrix<T>
+    where
+        T: Clone,
+    {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut data = Vec::with_capacity(self.data.len());
+        for col in 0..cols {
+            for row in (0..rows).rev() {
+                data.push(self.data[row * cols + col].clone());
+            }
+        }
+
+        DynamicMatrix {
+            data,
+            rows: cols,
+            cols: rows,
+        }
+    }
+
+    /// Returns the DynamicMatrix rotated a quarter turn counterclockwise
+    ///
+    /// An `r`×`c` DynamicMatrix becomes `c`×`r` with `rotated[(c - 1 - j, i)] == mat[(i, j)]`.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let rotated = mat.rotate_ccw();
+    /// assert_eq!(rotated.shape(), (3, 2));
+    /// assert_eq!(rotated.as_slice(), &[3, 6, 2, 5, 1, 4]);
+    ///
+    /// let back = rotated.rotate_cw();
+    /// assert_eq!(back.shape(), mat.shape());
+    /// assert_eq!(back.as_slice(), mat.as_slice());
+    /// assert_eq!(mat.rotate_cw().rotate_ccw().as_slice(), mat.as_slice());
+    /// ```
+    pub fn rotate_ccw(&self) -> DynamicMatrix<T>
+    where
+        T: Clone,
+    {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut data = Vec::with_capacity(self.data.len());
+        for col in (0..cols).rev() {
+            for row in 0..rows {
+                data.push(self.data[row * cols + col].clone());
+            }
+        }
+
+        DynamicMatrix {
+            data,
+            rows: cols,
+            cols: rows,
+        }
+    }
+
+    /// Rotates the DynamicMatrix a half turn in place, so that `mat[(r - 1 - i, c - 1 - j)]` ends up at `(i, j)`
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.rotate_180();
+    ///
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// assert_eq!(mat.as_slice(), &[6, 5, 4, 3, 2, 1]);
+    /// ```
+    pub fn rotate_180(&mut self) {
+        self.data.reverse();
+    }
+
+    /// Returns references to the elements on the main diagonal, stopping at the shorter of the two dimensions
     ///
     /// ```
     /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
     /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    /// assert_eq!(mat.diagonal(), vec![&1, &5, &9]);
     ///
-    /// for row in 0..mat.rows() {
-    ///     for col in 0..mat.cols() {
-    ///         assert_eq!(mat[(row, col)], 3 * row + col + 1);
-    ///     }
-    /// }
+    /// let wide = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(wide.diagonal(), vec![&1, &5]);
+    ///
+    /// let tall = dynamic_matrix![1, 2; 3, 4; 5, 6];
+    /// assert_eq!(tall.diagonal(), vec![&1, &4]);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert!(empty.diagonal().is_empty());
     /// ```
-    fn index(&self, index: (usize, usize)) -> &Self::Output {
-        self.get(index).unwrap()
+    pub fn diagonal(&self) -> Vec<&T> {
+        (0..self.rows.min(self.cols))
+            .map(|i| &self.data[i * self.cols + i])
+            .collect()
     }
-}
 
-impl<T> IndexMut<(usize, usize)> for DynamicMatrix<T> {
-    /// Returns an exclusive reference to the value at the given index
+    /// Returns a `Result` containing the contiguous slice backing the given row
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// assert_eq!(mat.row(2).unwrap().iter().sum::<isize>(), 24);
+    /// ```
+    ///
+    /// Indexing a row outside bounds will return an `IndexingError` about the row:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(0);
+    ///
+    /// assert_eq!(
+    ///     mat.row(0).unwrap_err().to_string(),
+    ///     "Attempted indexing row 0. The row index should be in [0, 0)\n"
+    /// );
+    /// ```
+    pub fn row(&self, row: usize) -> Result<&[T], IndexingError> {
+        if row < self.rows {
+            Ok(&self.data[row * self.cols..(row + 1) * self.cols])
+        } else {
+            Err(IndexingError::new_row_error(row, self.rows))
+        }
+    }
+
+    /// Returns a `Result` containing the contiguous mutable slice backing the given row
     ///
     /// ```
     /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
     /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
     ///
-    /// for row in 0..mat.rows() {
-    ///     for col in 0..mat.cols() {
-    ///         mat[(row, col)] += 9;
-    ///     }
+    /// mat.row_mut(0).unwrap().copy_from_slice(&[10, 20, 30]);
+    ///
+    /// assert_eq!(mat.as_slice(), &[10, 20, 30, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// let mut empty: DynamicMatrix<isize> = DynamicMatrix::filled((2, 0), 0);
+    /// assert!(empty.row_mut(1).unwrap().is_empty());
+    /// assert!(empty.row_mut(2).is_err());
+    /// ```
+    ///
+    /// Indexing a row outside bounds will return an `IndexingError`.
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// mat.row_mut(3).unwrap();
+    /// ```
+    pub fn row_mut(&mut self, row: usize) -> Result<&mut [T], IndexingError> {
+        if row < self.rows {
+            Ok(&mut self.data[row * self.cols..(row + 1) * self.cols])
+        } else {
+            Err(IndexingError::new_row_error(row, self.rows))
+        }
+    }
+
+    /// Returns a `Result` containing the given row as a slice
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// assert_eq!(mat.get_row(0).unwrap(), &[1, 2, 3]);
+    /// assert_eq!(mat.get_row(1).unwrap(), &[4, 5, 6]);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::filled((2, 0), 0);
+    /// assert!(empty.get_row(1).unwrap().is_empty());
+    /// assert!(empty.get_row(2).is_err());
+    /// ```
+    ///
+    /// Indexing a row outside bounds will return an `IndexingError`.
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.get_row(2).unwrap();
+    /// ```
+    pub fn get_row(&self, row: usize) -> Result<&[T], IndexingError> {
+        self.row(row)
+    }
+
+    /// Returns a `Result` containing references to the elements of the given column, from top to bottom
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// assert_eq!(mat.get_col(0).unwrap(), vec![&1, &4]);
+    /// assert_eq!(mat.get_col(2).unwrap(), vec![&3, &6]);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert!(empty.get_col(2).unwrap().is_empty());
+    /// assert!(empty.get_col(3).is_err());
+    /// ```
+    ///
+    /// Indexing a column outside bounds will return an `IndexingError`.
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.get_col(3).unwrap();
+    /// ```
+    pub fn get_col(&self, col: usize) -> Result<Vec<&T>, IndexingError> {
+        Ok(self.try_col_iter(col)?.collect())
+    }
+
+    /// Returns a `Result` containing a copy of the elements of the given column, from top to bottom
+    ///
+    /// Columns are not contiguous in row-major order, so this gathers the elements one row at a time and is O(rows).
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// assert_eq!(mat.col(1).unwrap(), vec![2, 5]);
+    ///
+    /// // Pushing the columns back one by one reproduces the original matrix
+    /// let mut rebuilt: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(0);
+    /// rebuilt.extend_rows(vec![vec![]; mat.rows()]).unwrap();
+    /// for col in 0..mat.cols() {
+    ///     rebuilt.push_col(mat.col(col).unwrap()).unwrap();
     /// }
     ///
-    /// assert_eq!(mat.as_slice(), &[10, 11, 12, 13, 14, 15, 16, 17, 18]);
+    /// assert_eq!(rebuilt.shape(), mat.shape());
+    /// assert_eq!(rebuilt.as_slice(), mat.as_slice());
     /// ```
-    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
-        self.get_mut(index).unwrap()
+    ///
+    /// Indexing a column outside bounds will return an `IndexingError`.
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.col(3).unwrap();
+    /// ```
+    pub fn col(&self, col: usize) -> Result<Vec<T>, IndexingError>
+    where
+        T: Clone,
+    {
+        Ok(self.try_col_iter(col)?.cloned().collect())
+    }
+
+    /// Returns an iterator over the elements in row-major order, the same order as [`as_slice`](Self::as_slice)
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// assert_eq!(mat.iter().max(), Some(&6));
+    /// assert!(mat.iter().eq(mat.as_slice().iter()));
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::filled((3, 0), 0);
+    /// assert_eq!(empty.iter().count(), 0);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Returns an iterator over exclusive references to the elements in row-major order
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// for e in mat.iter_mut() {
+    ///     *e *= 2;
+    /// }
+    ///
+    /// assert_eq!(mat.as_slice(), &[2, 4, 6, 8, 10, 12]);
+    ///
+    /// let mut empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.iter_mut().count(), 0);
+    /// ```
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Returns an iterator over the rows of the DynamicMatrix, each row given as a slice of length `cols()`
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let rows: Vec<&[isize]> = mat.rows_iter().collect();
+    /// assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+    ///
+    /// assert_eq!(mat.rows_iter().len(), 2);
+    /// assert_eq!(mat.rows_iter().next_back(), Some(&[4, 5, 6][..]));
+    /// ```
+    ///
+    /// A matrix with no rows yields nothing, while a matrix with no columns yields empty rows:
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mut mat: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(0);
+    /// assert_eq!(mat.rows_iter().next(), None);
+    ///
+    /// mat.push_row(vec![]).unwrap();
+    /// mat.push_row(vec![]).unwrap();
+    /// assert_eq!(mat.rows_iter().collect::<Vec<_>>(), vec![&[][..], &[][..]]);
+    /// ```
+    pub fn rows_iter(&self) -> RowsIter<'_, T> {
+        RowsIter::new(&self.data, self.rows, self.cols)
+    }
+
+    /// Returns an iterator over the rows of the DynamicMatrix, each row given as a mutable slice of length `cols()`
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// for (i, row) in mat.rows_iter_mut().enumerate() {
+    ///     row[0] = 10 * (i as isize + 1);
+    /// }
+    ///
+    /// // The borrow ends with the loop so rows can be pushed again
+    /// mat.push_row(vec![7, 8, 9]).unwrap();
+    ///
+    /// assert_eq!(mat.as_slice(), &[10, 2, 3, 20, 5, 6, 7, 8, 9]);
+    /// assert_eq!(mat.rows_iter_mut().rev().next(), Some(&mut [7, 8, 9][..]));
+    ///
+    /// let mut empty: DynamicMatrix<isize> = DynamicMatrix::filled((2, 0), 0);
+    /// assert!(empty.rows_iter_mut().all(|row| row.is_empty()));
+    /// assert_eq!(empty.rows_iter_mut().count(), 2);
+    /// ```
+    pub fn rows_iter_mut(&mut self) -> RowsIterMut<'_, T> {
+        RowsIterMut::new(&mut self.data, self.rows, self.cols)
+    }
+
+    /// Returns an iterator over the columns of the DynamicMatrix, each column given as an iterator over its elements
+    /// from top to bottom
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// let cols: Vec<Vec<&isize>> = mat.cols_iter().map(|col| col.collect()).collect();
+    ///
+    /// assert_eq!(cols.len(), 3);
+    /// assert_eq!(cols[0], vec![&1, &4, &7]);
+    /// assert_eq!(cols[2], vec![&3, &6, &9]);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.cols_iter().count(), 3);
+    /// assert!(empty.cols_iter().all(|mut col| col.next().is_none()));
+    /// ```
+    pub fn cols_iter(&self) -> impl ExactSizeIterator<Item = ColIter<'_, T>> + DoubleEndedIterator {
+        (0..self.cols).map(move |col| ColIter::new(&self.data, col, self.cols))
+    }
+
+    /// Returns a `Result` containing an iterator over the elements of the given column, from top to bottom
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// assert_eq!(mat.try_col_iter(0).unwrap().collect::<Vec<_>>(), vec![&1, &4]);
+    /// assert_eq!(mat.try_col_iter(2).unwrap().rev().collect::<Vec<_>>(), vec![&6, &3]);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.try_col_iter(2).unwrap().count(), 0);
+    /// assert!(empty.try_col_iter(3).is_err());
+    /// ```
+    ///
+    /// Indexing a column outside bounds will return an `IndexingError`.
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.try_col_iter(3).unwrap();
+    /// ```
+    pub fn try_col_iter(&self, col: usize) -> Result<ColIter<'_, T>, IndexingError> {
+        if col < self.cols {
+            Ok(ColIter::new(&self.data, col, self.cols))
+        } else {
+            Err(IndexingError::new_col_error(col, self.cols))
+        }
+    }
+
+    /// Returns an iterator over the elements of the given column, from top to bottom
+    ///
+    /// Panics if the column is out of bounds, see [`try_col_iter`](Self::try_col_iter) for a non-panicking variant.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// assert_eq!(mat.col_iter(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+    /// assert_eq!(mat.col_iter(1).len(), 2);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.col_iter(0).count(), 0);
+    /// ```
+    pub fn col_iter(&self, col: usize) -> ColIter<'_, T> {
+        self.try_col_iter(col).unwrap()
+    }
+
+    /// Returns a `Result` containing an iterator over mutable references to the elements of the given column, from
+    /// top to bottom
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9; 10, 11, 12];
+    ///
+    /// for e in mat.try_col_iter_mut(2).unwrap() {
+    ///     *e = 0;
+    /// }
+    ///
+    /// assert_eq!(mat.as_slice(), &[1, 2, 0, 4, 5, 0, 7, 8, 0, 10, 11, 0]);
+    ///
+    /// let mut empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.try_col_iter_mut(2).unwrap().count(), 0);
+    /// assert!(empty.try_col_iter_mut(3).is_err());
+    /// ```
+    ///
+    /// Indexing a column outside bounds will return an `IndexingError`.
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.try_col_iter_mut(3).unwrap();
+    /// ```
+    pub fn try_col_iter_mut(&mut self, col: usize) -> Result<ColIterMut<'_, T>, IndexingError> {
+        if col < self.cols {
+            Ok(ColIterMut::new(&mut self.data, col, self.cols))
+        } else {
+            Err(IndexingError::new_col_error(col, self.cols))
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements of the given column, from top to bottom
+    ///
+    /// Panics if the column is out of bounds, see [`try_col_iter_mut`](Self::try_col_iter_mut) for a non-panicking
+    /// variant.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2; 3, 4; 5, 6];
+    ///
+    /// for e in mat.col_iter_mut(0) {
+    ///     *e *= 10;
+    /// }
+    ///
+    /// assert_eq!(mat.as_slice(), &[10, 2, 30, 4, 50, 6]);
+    ///
+    /// let mut empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.col_iter_mut(0).count(), 0);
+    /// ```
+    pub fn col_iter_mut(&mut self, col: usize) -> ColIterMut<'_, T> {
+        self.try_col_iter_mut(col).unwrap()
+    }
+
+    /// Returns an iterator over the elements in row-major order, each paired with its index as a row, column tuple
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// for ((row, col), e) in mat.indexed_iter() {
+    ///     assert_eq!(mat[(row, col)], *e);
+    /// }
+    ///
+    /// let indices: Vec<(usize, usize)> = mat.indexed_iter().map(|(index, _)| index).collect();
+    /// assert_eq!(indices, vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(0);
+    /// assert_eq!(empty.indexed_iter().next(), None);
+    /// ```
+    pub fn indexed_iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = ((usize, usize), &T)> + DoubleEndedIterator {
+        let cols = self.cols;
+
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(i, e)| ((i / cols, i % cols), e))
+    }
+
+    /// Returns an iterator over exclusive references to the elements in row-major order, each paired with its index
+    /// as a row, column tuple
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![0, 0, 0; 0, 0, 0];
+    ///
+    /// for ((row, col), e) in mat.indexed_iter_mut() {
+    ///     *e = 10 * row + col;
+    /// }
+    ///
+    /// assert_eq!(mat.as_slice(), &[0, 1, 2, 10, 11, 12]);
+    ///
+    /// let mut empty: DynamicMatrix<isize> = DynamicMatrix::filled((2, 0), 0);
+    /// assert_eq!(empty.indexed_iter_mut().count(), 0);
+    /// ```
+    pub fn indexed_iter_mut(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = ((usize, usize), &mut T)> + DoubleEndedIterator {
+        let cols = self.cols;
+
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, e)| ((i / cols, i % cols), e))
+    }
+
+    /// Returns an iterator over the elements in row-major order, each paired with whether it lies on the main
+    /// diagonal
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// let diagonal: Vec<&isize> = mat
+    ///     .iter_with_diagonal_flag()
+    ///     .filter(|(_, on_diagonal)| *on_diagonal)
+    ///     .map(|(e, _)| e)
+    ///     .collect();
+    ///
+    /// assert_eq!(diagonal, vec![&1, &5, &9]);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(0);
+    /// assert_eq!(empty.iter_with_diagonal_flag().count(), 0);
+    /// ```
+    pub fn iter_with_diagonal_flag(&self) -> impl Iterator<Item = (&T, bool)> + '_ {
+        self.indexed_iter().map(|((row, col), e)| (e, row == col))
+    }
+
+    /// Returns the transpose of the DynamicMatrix, so that `transposed[(j, i)] == mat[(i, j)]`
+    ///
+    /// The elements are copied tile by tile so that both the reads and the writes stay within a few cache lines.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let transposed = mat.transpose();
+    /// assert_eq!(transposed.shape(), (3, 2));
+    /// assert_eq!(transposed.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    ///
+    /// let row = dynamic_matrix![1, 2, 3, 4];
+    /// assert_eq!(row.transpose().shape(), (4, 1));
+    /// assert_eq!(row.transpose().as_slice(), row.as_slice());
+    ///
+    /// let col = row.transpose();
+    /// assert_eq!(col.transpose().shape(), (1, 4));
+    /// ```
+    ///
+    /// Dimensions that are not a multiple of the tile size leave partial tiles along the edges:
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// for (rows, cols) in [(70, 45), (33, 65), (31, 1), (1, 100)] {
+    ///     let mat = DynamicMatrix::from_fn((rows, cols), |row, col| row * cols + col);
+    ///     let transposed = mat.transpose();
+    ///
+    ///     assert_eq!(transposed.shape(), (cols, rows));
+    ///     for ((row, col), e) in mat.indexed_iter() {
+    ///         assert_eq!(transposed[(col, row)], *e);
+    ///     }
+    /// }
+    /// ```
+    pub fn transpose(&self) -> DynamicMatrix<T>
+    where
+        T: Clone,
+    {
+        const TILE: usize = 32;
+
+        let (rows, cols) = (self.rows, self.cols);
+        let mut out: Vec<Option<T>> = Vec::with_capacity(self.data.len());
+        out.resize_with(self.data.len(), || None);
+        for row_start in (0..rows).step_by(TILE) {
+            for col_start in (0..cols).step_by(TILE) {
+                for row in row_start..(row_start + TILE).min(rows) {
+                    for col in col_start..(col_start + TILE).min(cols) {
+                        out[col * rows + row] = Some(self.data[row * cols + col].clone());
+                    }
+                }
+            }
+        }
+
+        // The tiles cover every index exactly once, so every slot has been filled
+        DynamicMatrix {
+            data: out.into_iter().flatten().collect(),
+            rows: cols,
+            cols: rows,
+        }
+    }
+
+    /// Transposes the DynamicMatrix without allocating a second buffer for the elements
+    ///
+    /// Square matrices are transposed by swapping across the diagonal. Rectangular ones have their elements moved
+    /// along the cycles of the transposition permutation, keeping a bitmap of the positions already placed.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    /// mat.transpose_in_place();
+    /// assert_eq!(mat.as_slice(), &[1, 4, 7, 2, 5, 8, 3, 6, 9]);
+    ///
+    /// for shape in [(3, 7), (7, 3), (1, 5), (5, 1), (4, 6), (0, 3)] {
+    ///     let mut mat = DynamicMatrix::filled(shape, 0);
+    ///     for ((i, j), e) in mat.indexed_iter_mut() {
+    ///         *e = i * 31 + j * 17 % 11;
+    ///     }
+    ///     let expected = mat.transpose();
+    ///
+    ///     mat.transpose_in_place();
+    ///
+    ///     assert_eq!(mat.shape(), expected.shape());
+    ///     assert_eq!(mat.as_slice(), expected.as_slice());
+    /// }
+    /// ```
+    pub fn transpose_in_place(&mut self) {
+        let (rows, cols) = (self.rows, self.cols);
+
+        if rows == cols {
+            for row in 0..rows {
+                for col in (row + 1)..cols {
+                    self.data.swap(row * cols + col, col * cols + row);
+                }
+            }
+        } else if rows > 1 && cols > 1 {
+            // The element at flat index p < len - 1 moves to p * rows mod (len - 1), the first and last stay put
+            let last = self.data.len() - 1;
+            let mut placed = vec![false; last];
+            for start in 1..last {
+                if placed[start] {
+                    continue;
+                }
+
+                placed[start] = true;
+                let mut next = start * rows % last;
+                while next != start {
+                    self.data.swap(start, next);
+                    placed[next] = true;
+                    next = next * rows % last;
+                }
+            }
+        }
+
+        self.rows = cols;
+        self.cols = rows;
+    }
+
+    /// Consumes the DynamicMatrix, applying `f` to every element and returning the results as a new DynamicMatrix of
+    /// the same shape
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let halves: DynamicMatrix<f64> = mat.map(|x| x as f64 * 0.5);
+    ///
+    /// assert_eq!(halves.shape(), (2, 3));
+    /// assert_eq!(halves.as_slice(), &[0.5, 1.0, 1.5, 2.0, 2.5, 3.0]);
+    ///
+    /// let empty: DynamicMatrix<i32> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.map(|x| x as f64).shape(), (0, 3));
+    /// ```
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> DynamicMatrix<U> {
+        DynamicMatrix {
+            data: self.data.into_iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Applies `f` to a reference to every element and returns the results as a new DynamicMatrix of the same shape
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let strings = mat.map_ref(|x| x.to_string());
+    /// let expected = dynamic_matrix!["1", "2", "3"; "4", "5", "6"];
+    ///
+    /// assert_eq!(strings.shape(), expected.shape());
+    /// assert!(strings.iter().eq(expected.iter()));
+    /// assert_eq!(mat.shape(), (2, 3));
+    ///
+    /// let empty: DynamicMatrix<i32> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.map_ref(|&x| x as f64).shape(), (0, 3));
+    /// ```
+    pub fn map_ref<U, F: FnMut(&T) -> U>(&self, f: F) -> DynamicMatrix<U> {
+        DynamicMatrix {
+            data: self.data.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Consumes the DynamicMatrix, applying `f` to the `(row, column)` index and value of every element and returning
+    /// the results as a new DynamicMatrix of the same shape
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let weighted: DynamicMatrix<f64> = mat.map_indexed(|(row, col), x| (x * (row + col) as i32) as f64);
+    ///
+    /// assert_eq!(weighted.shape(), (2, 3));
+    /// assert_eq!(weighted.as_slice(), &[0.0, 2.0, 6.0, 4.0, 10.0, 18.0]);
+    ///
+    /// let empty: DynamicMatrix<i32> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.map_indexed(|_, x| x as f64).shape(), (0, 3));
+    /// ```
+    pub fn map_indexed<U, F: FnMut((usize, usize), T) -> U>(self, mut f: F) -> DynamicMatrix<U> {
+        let cols = self.cols;
+        DynamicMatrix {
+            data: self
+                .data
+                .into_iter()
+                .enumerate()
+                .map(|(i, e)| f((i / cols, i % cols), e))
+                .collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Applies `f` to every pair of corresponding elements of two DynamicMatrix of the same shape and returns the
+    /// results as a new DynamicMatrix
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let values = dynamic_matrix![1.5, -2.0; 3.0, 4.5];
+    /// let mask = dynamic_matrix![true, false; false, true];
+    ///
+    /// let masked = values.zip_map(&mask, |&x, &keep| if keep { x } else { 0.0 });
+    ///
+    /// assert_eq!(masked.unwrap().as_slice(), &[1.5, 0.0, 0.0, 4.5]);
+    /// ```
+    ///
+    /// Combining matrices of different shapes will return a `ShapeError`:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// let b = dynamic_matrix![1, 2; 3, 4];
+    ///
+    /// assert_eq!(
+    ///     a.zip_map(&b, |&x, &y| x.max(y)).unwrap_err().to_string(),
+    ///     "The operation performed expected 3 cols but the matrix has 2.\n"
+    /// );
+    /// ```
+    pub fn zip_map<U, V, F: FnMut(&T, &U) -> V>(
+        &self,
+        other: &DynamicMatrix<U>,
+        mut f: F,
+    ) -> Result<DynamicMatrix<V>, ShapeError> {
+        if self.shape() != other.shape() {
+            return Err(ShapeError::new(other.shape(), self.shape()));
+        }
+
+        Ok(DynamicMatrix {
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(a, b)| f(a, b))
+                .collect(),
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+
+    /// Consumes the DynamicMatrix, replacing every element with the result of `f` on it and the corresponding element
+    /// of `other`, reusing the allocation of the DynamicMatrix
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1, 7, 3; 4, 2, 6];
+    /// let b = dynamic_matrix![5, 2, 3; 1, 8, 0];
+    ///
+    /// let max = a.zip_map_into(&b, |&x, &y| x.max(y)).unwrap();
+    ///
+    /// assert_eq!(max.as_slice(), &[5, 7, 3, 4, 8, 6]);
+    /// ```
+    ///
+    /// Combining matrices of different shapes will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// let b = dynamic_matrix![1, 2; 3, 4];
+    ///
+    /// a.zip_map_into(&b, |x, y| x + y).unwrap();
+    /// ```
+    pub fn zip_map_into<U, F: FnMut(&T, &U) -> T>(
+        mut self,
+        other: &DynamicMatrix<U>,
+        mut f: F,
+    ) -> Result<DynamicMatrix<T>, ShapeError> {
+        if self.shape() != other.shape() {
+            return Err(ShapeError::new(other.shape(), self.shape()));
+        }
+
+        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
+            *a = f(a, b);
+        }
+
+        Ok(self)
+    }
+
+    /// Calls `f` on every element in row-major order, mutating the DynamicMatrix in place
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.apply(|e| *e += 1);
+    ///
+    /// assert_eq!(mat.as_slice(), &[2, 3, 4, 5, 6, 7]);
+    /// ```
+    pub fn apply<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.iter_mut().for_each(f);
+    }
+
+    /// Overwrites every element of the DynamicMatrix with a clone of `value`, keeping its shape
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.fill(0);
+    ///
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// assert_eq!(mat.as_slice(), &[0, 0, 0, 0, 0, 0]);
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.as_mut_slice().fill(value);
+    }
+
+    /// Overwrites every element of the DynamicMatrix with the values returned by `f`, called in row-major order
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![0, 0, 0; 0, 0, 0];
+    ///
+    /// let mut next = 0;
+    /// mat.fill_with(|| {
+    ///     next += 10;
+    ///     next
+    /// });
+    ///
+    /// assert_eq!(mat.as_slice(), &[10, 20, 30, 40, 50, 60]);
+    /// ```
+    pub fn fill_with<F: FnMut() -> T>(&mut self, f: F) {
+        self.as_mut_slice().fill_with(f);
+    }
+
+    /// Folds every element into an accumulator in row-major order, returning `init` for an empty DynamicMatrix
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![3, 1, 4; 1, 5, 9];
+    ///
+    /// assert_eq!(mat.fold(0, |acc, e| acc + e), 23);
+    /// assert_eq!(mat.fold(i32::MIN, |acc, &e| acc.max(e)), 9);
+    ///
+    /// let empty: DynamicMatrix<i32> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.fold(7, |acc, e| acc + e), 7);
+    /// ```
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+        self.data.iter().fold(init, f)
+    }
+
+    /// Returns a `(row, column, value)` triplet for every element unequal to `zero`, in row-major order
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![0, 0, 3; 0, 0, 0; 7, 0, 0];
+    ///
+    /// let triplets = mat.to_triplets(&0);
+    /// assert_eq!(triplets, vec![(0, 2, 3), (2, 0, 7)]);
+    ///
+    /// let back = DynamicMatrix::from_triplets(mat.shape(), triplets, 0).unwrap();
+    /// assert_eq!(back.shape(), mat.shape());
+    /// assert_eq!(back.as_slice(), mat.as_slice());
+    /// ```
+    pub fn to_triplets(&self, zero: &T) -> Vec<(usize, usize, T)>
+    where
+        T: PartialEq + Clone,
+    {
+        self.indexed_iter()
+            .filter(|(_, e)| *e != zero)
+            .map(|((row, col), e)| (row, col, e.clone()))
+            .collect()
+    }
+
+    /// Builds a DynamicMatrix of the given shape filled with `zero`, then places every `(row, column, value)`
+    /// triplet; later triplets overwrite earlier ones at the same index
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = DynamicMatrix::from_triplets((2, 3), vec![(1, 1, 5), (0, 2, 2)], 0).unwrap();
+    ///
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// assert_eq!(mat.as_slice(), &[0, 0, 2, 0, 5, 0]);
+    /// assert_eq!(mat.to_triplets(&0), vec![(0, 2, 2), (1, 1, 5)]);
+    /// ```
+    ///
+    /// A triplet outside the bounds of the shape will return an `IndexingError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// DynamicMatrix::from_triplets((2, 3), vec![(2, 0, 1)], 0).unwrap();
+    /// ```
+    pub fn from_triplets<I>(
+        shape: (usize, usize),
+        triplets: I,
+        zero: T,
+    ) -> Result<Self, IndexingError>
+    where
+        I: IntoIterator<Item = (usize, usize, T)>,
+        T: Clone,
+    {
+        let mut mat = Self::filled(shape, zero);
+        for (row, col, value) in triplets {
+            *mat.get_mut((row, col))? = value;
+        }
+
+        Ok(mat)
+    }
+}
+
+impl<T> Index<(usize, usize)> for DynamicMatrix<T> {
+    type Output = T;
+
+    /// Returns a shared reference to the value at the given index
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// for row in 0..mat.rows() {
+    ///     for col in 0..mat.cols() {
+    ///         assert_eq!(mat[(row, col)], 3 * row + col + 1);
+    ///     }
+    /// }
+    /// ```
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for DynamicMatrix<T> {
+    /// Returns an exclusive reference to the value at the given index
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// for row in 0..mat.rows() {
+    ///     for col in 0..mat.cols() {
+    ///         mat[(row, col)] += 9;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(mat.as_slice(), &[10, 11, 12, 13, 14, 15, 16, 17, 18]);
+    /// ```
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(index).unwrap()
+    }
+}
+
+impl<T> Index<usize> for DynamicMatrix<T> {
+    type Output = [T];
+
+    /// Returns the given row as a slice
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// assert_eq!(&mat[1], &[4, 5, 6]);
+    ///
+    /// for row in 0..mat.rows() {
+    ///     for col in 0..mat.cols() {
+    ///         assert_eq!(mat[row][col], mat[(row, col)]);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Indexing a row outside bounds panics with the `IndexingError` message:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// let _ = &mat[3];
+    /// ```
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// assert_eq!(
+    ///     mat.row(3).unwrap_err().to_string(),
+    ///     "Attempted indexing row 3. The row index should be in [0, 3)\n"
+    /// );
+    /// ```
+    fn index(&self, index: usize) -> &Self::Output {
+        self.row(index).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+impl<T> IndexMut<usize> for DynamicMatrix<T> {
+    /// Returns the given row as a mutable slice
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// mat[2][1] = 80;
+    /// mat[0].copy_from_slice(&[10, 20, 30]);
+    ///
+    /// assert_eq!(mat.as_slice(), &[10, 20, 30, 4, 5, 6, 7, 80, 9]);
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.row_mut(index).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+impl<T> IntoIterator for DynamicMatrix<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    /// Consumes the DynamicMatrix and returns an iterator over its elements in row-major order
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let mut elems = Vec::new();
+    /// for e in mat {
+    ///     elems.push(e);
+    /// }
+    ///
+    /// assert_eq!(elems, vec![1, 2, 3, 4, 5, 6]);
+    ///
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(mat.into_iter().sum::<isize>(), 21);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DynamicMatrix<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    /// Returns an iterator over shared references to the elements in row-major order
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let mut sum = 0;
+    /// for e in &mat {
+    ///     sum += e;
+    /// }
+    ///
+    /// // The matrix can still be used after iterating over it
+    /// assert_eq!(sum, 21);
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut DynamicMatrix<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    /// Returns an iterator over exclusive references to the elements in row-major order
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// for e in &mut mat {
+    ///     *e *= 2;
+    /// }
+    ///
+    /// assert_eq!(mat.as_slice(), &[2, 4, 6, 8, 10, 12]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
+impl<T> FromIterator<Vec<T>> for DynamicMatrix<T> {
+    /// Collects an iterator of rows into a DynamicMatrix, taking the number of columns from the first row
+    ///
+    /// Panics if the rows have unequal number of columns, see
+    /// [`try_from_rows`](DynamicMatrix::try_from_rows) for a non-panicking variant.
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat: DynamicMatrix<usize> = (1..=3).map(|i| vec![i, 10 * i]).collect();
+    ///
+    /// assert_eq!(mat.shape(), (3, 2));
+    /// assert_eq!(mat.as_slice(), &[1, 10, 2, 20, 3, 30]);
+    /// ```
+    ///
+    /// Collecting ragged rows panics, use [`try_from_rows`](DynamicMatrix::try_from_rows) to get
+    /// the ShapeError instead.
+    /// ```should_panic
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat: DynamicMatrix<usize> = (1..=3).map(|i| vec![0; i]).collect();
+    /// ```
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
+        Self::try_from_rows(iter).unwrap()
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for DynamicMatrix<T> {
+    type Error = ShapeError;
+
+    /// Constructs a DynamicMatrix from nested rows, taking the number of columns from the first row
+    ///
+    /// An empty Vec gives a matrix with no rows and no columns.
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat = DynamicMatrix::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    ///
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// let empty = DynamicMatrix::<isize>::try_from(Vec::<Vec<isize>>::new()).unwrap();
+    /// assert_eq!(empty.shape(), (0, 0));
+    /// ```
+    ///
+    /// A ragged row will return a `ShapeError`:
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let res = DynamicMatrix::try_from(vec![vec![1, 2, 3], vec![4, 5]]);
+    ///
+    /// assert_eq!(
+    ///     res.unwrap_err().to_string(),
+    ///     "The operation performed expected 2 cols but the matrix has 3.\n"
+    /// );
+    /// ```
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        Self::try_from_rows(rows)
+    }
+}
+
+impl<T> From<DynamicMatrix<T>> for Vec<Vec<T>> {
+    /// Consumes the DynamicMatrix and splits its elements into one Vec per row
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let nested = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    ///
+    /// let mat = DynamicMatrix::try_from(nested.clone()).unwrap();
+    /// assert_eq!(Vec::from(mat), nested);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert!(Vec::from(empty).is_empty());
+    /// ```
+    fn from(mat: DynamicMatrix<T>) -> Self {
+        let cols = mat.cols;
+        let mut data = mat.data.into_iter();
+
+        (0..mat.rows)
+            .map(|_| data.by_ref().take(cols).collect())
+            .collect()
+    }
+}
+
+impl<T> Extend<Vec<T>> for DynamicMatrix<T> {
+    /// Appends all the rows of an iterator to the DynamicMatrix
+    ///
+    /// Panics if any of the rows has an unequal number of columns, see
+    /// [`extend_rows`](DynamicMatrix::extend_rows) for a non-panicking variant.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2];
+    ///
+    /// mat.extend((3..=4).map(|i| vec![2 * i - 3, 2 * i - 2]));
+    ///
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn extend<I: IntoIterator<Item = Vec<T>>>(&mut self, iter: I) {
+        self.extend_rows(iter).unwrap()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for DynamicMatrix<T> {
+    /// Formats the DynamicMatrix as a grid with one bracketed row per line and every element right-aligned to the
+    /// width of the longest one
+    ///
+    /// A width given to the formatter is used as the minimum width of every element and a precision is passed on to
+    /// the elements. A DynamicMatrix without elements is formatted as `[]`.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 50, 6];
+    /// assert_eq!(mat.to_string(), "[ 1  2  3]\n[ 4 50  6]");
+    ///
+    /// let mat = dynamic_matrix![1.0, 2.5; -3.25, 4.0];
+    /// assert_eq!(format!("{:.1}", mat), "[ 1.0  2.5]\n[-3.2  4.0]");
+    /// assert_eq!(format!("{:5}", dynamic_matrix![1, 2]), "[    1     2]");
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.to_string(), "[]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.data.is_empty() {
+            return write!(f, "[]");
+        }
+
+        let elements: Vec<String> = self
+            .data
+            .iter()
+            .map(|e| match f.precision() {
+                Some(precision) => format!("{:.*}", precision, e),
+                None => e.to_string(),
+            })
+            .collect();
+        let width = elements
+            .iter()
+            .map(|e| e.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(f.width().unwrap_or(0));
+
+        for (i, row) in elements.chunks(self.cols).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "[")?;
+            for (j, e) in row.iter().enumerate() {
+                if j > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{:>width$}", e, width = width)?;
+            }
+            write!(f, "]")?;
+        }
+
+        Ok(())
     }
 }
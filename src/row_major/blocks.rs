@@ -0,0 +1,280 @@
+use std::ops::Range;
+
+use super::DynamicMatrix;
+use crate::errors::{indexing_error::IndexingError, shape_error::ShapeError};
+
+impl<T> DynamicMatrix<T> {
+    /// Assembles a DynamicMatrix from a grid of blocks, given as a slice of block-rows
+    ///
+    /// All the blocks in a block-row must have the same number of rows and all the blocks in a block-column must have
+    /// the same number of columns.
+    ///
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat = DynamicMatrix::from_blocks(&[
+    ///     vec![DynamicMatrix::new([[1]]), DynamicMatrix::new([[2, 3]])],
+    ///     vec![DynamicMatrix::new([[4]]), DynamicMatrix::new([[5, 6]])],
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    ///
+    /// Blocks with inconsistent shapes will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// DynamicMatrix::from_blocks(&[
+    ///     vec![DynamicMatrix::new([[1]]), DynamicMatrix::new([[2, 3]])],
+    ///     vec![DynamicMatrix::new([[4, 5]]), DynamicMatrix::new([[6]])],
+    /// ])
+    /// .unwrap();
+    /// ```
+    ///
+    /// So will a block-row with fewer or more blocks than the first one, reported as the shape of the block-row:
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let err = DynamicMatrix::from_blocks(&[
+    ///     vec![DynamicMatrix::new([[1]]), DynamicMatrix::new([[2, 3]])],
+    ///     vec![DynamicMatrix::new([[4]])],
+    /// ])
+    /// .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "The operation performed expected 3 cols but the matrix has 1.\n"
+    /// );
+    /// ```
+    pub fn from_blocks(blocks: &[Vec<DynamicMatrix<T>>]) -> Result<DynamicMatrix<T>, ShapeError>
+    where
+        T: Clone,
+    {
+        let widths: Vec<usize> = match blocks.first() {
+            Some(block_row) => block_row.iter().map(|block| block.cols).collect(),
+            None => Vec::new(),
+        };
+
+        let cols = widths.iter().sum();
+
+        let mut rows = 0;
+        for block_row in blocks {
+            let height = block_row.first().map_or(0, |block| block.rows);
+            for (block, &width) in block_row.iter().zip(widths.iter()) {
+                if block.shape() != (height, width) {
+                    return Err(ShapeError::new(block.shape(), (height, width)));
+                }
+            }
+
+            if block_row.len() != widths.len() {
+                let width = block_row.iter().map(|block| block.cols).sum();
+                return Err(ShapeError::new((height, width), (height, cols)));
+            }
+
+            rows += height;
+        }
+
+        let mut data = Vec::with_capacity(rows * cols);
+        for block_row in blocks {
+            let height = block_row.first().map_or(0, |block| block.rows);
+            for row in 0..height {
+                for block in block_row {
+                    data.extend_from_slice(&block.data[row * block.cols..(row + 1) * block.cols]);
+                }
+            }
+        }
+
+        Ok(DynamicMatrix { data, rows, cols })
+    }
+
+    /// Checks that the DynamicMatrix can be evenly divided into blocks of the given shape and returns the number of
+    /// blocks along each dimension
+    fn block_grid(&self, block: (usize, usize)) -> Result<(usize, usize), ShapeError> {
+        let (block_rows, block_cols) = block;
+        if block_rows == 0 || block_cols == 0 {
+            return Err(ShapeError::new_block_error(block));
+        }
+
+        if self.rows % block_rows != 0 || self.cols % block_cols != 0 {
+            return Err(ShapeError::new(
+                self.shape(),
+                (
+                    self.rows.next_multiple_of(block_rows),
+                    self.cols.next_multiple_of(block_cols),
+                ),
+            ));
+        }
+
+        Ok((self.rows / block_rows, self.cols / block_cols))
+    }
+
+    /// Copies the block of the given shape whose top left element is at the given index
+    fn copy_block(&self, top_left: (usize, usize), block: (usize, usize)) -> DynamicMatrix<T>
+    where
+        T: Clone,
+    {
+        let (top, left) = top_left;
+        let (block_rows, block_cols) = block;
+
+        let mut data = Vec::with_capacity(block_rows * block_cols);
+        for row in top..top + block_rows {
+            let start = row * self.cols + left;
+            data.extend_from_slice(&self.data[start..start + block_cols]);
+        }
+
+        DynamicMatrix {
+            data,
+            rows: block_rows,
+            cols: block_cols,
+        }
+    }
+
+    /// Returns a `Result` containing a copy of the block spanning the given ranges of rows and columns
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3, 4; 5, 6, 7, 8; 9, 10, 11, 12];
+    ///
+    /// let corner = mat.submatrix(0..2, 2..4).unwrap();
+    /// assert_eq!(corner.shape(), (2, 2));
+    /// assert_eq!(corner.as_slice(), &[3, 4, 7, 8]);
+    ///
+    /// let interior = mat.submatrix(1..2, 1..3).unwrap();
+    /// assert_eq!(interior.as_slice(), &[6, 7]);
+    ///
+    /// let empty = mat.submatrix(1..1, 0..3).unwrap();
+    /// assert_eq!(empty.shape(), (0, 3));
+    /// ```
+    ///
+    /// A range reaching outside bounds will return an `IndexingError`.
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3, 4; 5, 6, 7, 8; 9, 10, 11, 12];
+    ///
+    /// mat.submatrix(1..4, 0..2).unwrap();
+    /// ```
+    pub fn submatrix(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Result<DynamicMatrix<T>, IndexingError>
+    where
+        T: Clone,
+    {
+        let row_end = rows.end.max(rows.start);
+        if row_end > self.rows {
+            return Err(IndexingError::new_row_error(row_end - 1, self.rows));
+        }
+        let col_end = cols.end.max(cols.start);
+        if col_end > self.cols {
+            return Err(IndexingError::new_col_error(col_end - 1, self.cols));
+        }
+
+        Ok(self.copy_block((rows.start, cols.start), (rows.len(), cols.len())))
+    }
+
+    /// Returns a `Result` containing an iterator over the non-overlapping blocks of the given shape, in row-major
+    /// order of the blocks
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![
+    ///      1,  2,  3,  4;
+    ///      5,  6,  7,  8;
+    ///      9, 10, 11, 12;
+    ///     13, 14, 15, 16;
+    /// ];
+    ///
+    /// let blocks: Vec<DynamicMatrix<isize>> = mat.iter_blocks((2, 2)).unwrap().collect();
+    ///
+    /// assert_eq!(blocks.len(), 4);
+    /// assert_eq!(blocks[0].shape(), (2, 2));
+    /// assert_eq!(blocks[0].as_slice(), &[1, 2, 5, 6]);
+    /// assert_eq!(blocks[1].as_slice(), &[3, 4, 7, 8]);
+    /// assert_eq!(blocks[3].as_slice(), &[11, 12, 15, 16]);
+    /// ```
+    ///
+    /// Using a block shape that does not evenly divide the shape of the matrix will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.iter_blocks((2, 2)).unwrap();
+    /// ```
+    ///
+    /// So will a block shape with no elements:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2; 3, 4];
+    ///
+    /// let err = mat.iter_blocks((0, 2)).err().unwrap();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "The operation performed expected a block of at least 1 element but the block has shape (0, 2).\n"
+    /// );
+    /// ```
+    pub fn iter_blocks(
+        &self,
+        block: (usize, usize),
+    ) -> Result<impl Iterator<Item = DynamicMatrix<T>> + '_, ShapeError>
+    where
+        T: Clone,
+    {
+        let (grid_rows, grid_cols) = self.block_grid(block)?;
+
+        Ok((0..grid_rows * grid_cols).map(move |i| {
+            let (block_row, block_col) = (i / grid_cols, i % grid_cols);
+            self.copy_block((block_row * block.0, block_col * block.1), block)
+        }))
+    }
+
+    /// Treats the DynamicMatrix as a grid of blocks of the given shape and transposes the arrangement of the blocks,
+    /// keeping the layout within every block intact
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![
+    ///      1,  2,  3,  4;
+    ///      5,  6,  7,  8;
+    ///      9, 10, 11, 12;
+    ///     13, 14, 15, 16;
+    /// ];
+    ///
+    /// let transposed = mat.block_transpose((2, 2)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     transposed.as_slice(),
+    ///     &[1, 2, 9, 10, 5, 6, 13, 14, 3, 4, 11, 12, 7, 8, 15, 16]
+    /// );
+    /// ```
+    ///
+    /// Using a block shape that does not evenly divide the shape of the matrix will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.block_transpose((2, 2)).unwrap();
+    /// ```
+    pub fn block_transpose(&self, block: (usize, usize)) -> Result<DynamicMatrix<T>, ShapeError>
+    where
+        T: Clone,
+    {
+        let (grid_rows, grid_cols) = self.block_grid(block)?;
+        let (block_rows, block_cols) = block;
+
+        let mut data = Vec::with_capacity(self.data.len());
+        for block_col in 0..grid_cols {
+            for row in 0..block_rows {
+                for block_row in 0..grid_rows {
+                    let start = (block_row * block_rows + row) * self.cols + block_col * block_cols;
+                    data.extend_from_slice(&self.data[start..start + block_cols]);
+                }
+            }
+        }
+
+        Ok(DynamicMatrix {
+            data,
+            rows: grid_cols * block_rows,
+            cols: grid_rows * block_cols,
+        })
+    }
+}
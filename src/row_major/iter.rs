@@ -0,0 +1,192 @@
+//! Iterators over the elements of a DynamicMatrix
+
+use std::{
+    iter::{FusedIterator, Skip, StepBy},
+    mem, slice,
+};
+
+#[derive(Debug)]
+/// An iterator over the rows of a DynamicMatrix as slices
+///
+/// This struct is created by [`DynamicMatrix::rows_iter`](crate::DynamicMatrix::rows_iter).
+pub struct RowsIter<'a, T> {
+    data: &'a [T],
+    rows: usize,
+    cols: usize,
+}
+
+impl<'a, T> RowsIter<'a, T> {
+    pub(crate) fn new(data: &'a [T], rows: usize, cols: usize) -> Self {
+        Self { data, rows, cols }
+    }
+}
+
+impl<'a, T> Iterator for RowsIter<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            return None;
+        }
+
+        let (row, rest) = self.data.split_at(self.cols);
+        self.data = rest;
+        self.rows -= 1;
+
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rows, Some(self.rows))
+    }
+}
+
+impl<T> DoubleEndedIterator for RowsIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            return None;
+        }
+
+        let (rest, row) = self.data.split_at(self.data.len() - self.cols);
+        self.data = rest;
+        self.rows -= 1;
+
+        Some(row)
+    }
+}
+
+impl<T> ExactSizeIterator for RowsIter<'_, T> {}
+
+impl<T> FusedIterator for RowsIter<'_, T> {}
+
+#[derive(Debug)]
+/// An iterator over the rows of a DynamicMatrix as mutable slices
+///
+/// This struct is created by [`DynamicMatrix::rows_iter_mut`](crate::DynamicMatrix::rows_iter_mut).
+pub struct RowsIterMut<'a, T> {
+    data: &'a mut [T],
+    rows: usize,
+    cols: usize,
+}
+
+impl<'a, T> RowsIterMut<'a, T> {
+    pub(crate) fn new(data: &'a mut [T], rows: usize, cols: usize) -> Self {
+        Self { data, rows, cols }
+    }
+}
+
+impl<'a, T> Iterator for RowsIterMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            return None;
+        }
+
+        let (row, rest) = mem::take(&mut self.data).split_at_mut(self.cols);
+        self.data = rest;
+        self.rows -= 1;
+
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rows, Some(self.rows))
+    }
+}
+
+impl<T> DoubleEndedIterator for RowsIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            return None;
+        }
+
+        let data = mem::take(&mut self.data);
+        let split = data.len() - self.cols;
+        let (rest, row) = data.split_at_mut(split);
+        self.data = rest;
+        self.rows -= 1;
+
+        Some(row)
+    }
+}
+
+impl<T> ExactSizeIterator for RowsIterMut<'_, T> {}
+
+impl<T> FusedIterator for RowsIterMut<'_, T> {}
+
+#[derive(Debug)]
+/// An iterator over the elements of a single column of a DynamicMatrix
+///
+/// This struct is created by [`DynamicMatrix::col_iter`](crate::DynamicMatrix::col_iter).
+pub struct ColIter<'a, T> {
+    inner: StepBy<Skip<slice::Iter<'a, T>>>,
+}
+
+impl<'a, T> ColIter<'a, T> {
+    pub(crate) fn new(data: &'a [T], col: usize, cols: usize) -> Self {
+        Self {
+            inner: data.iter().skip(col).step_by(cols),
+        }
+    }
+}
+
+impl<'a, T> Iterator for ColIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for ColIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for ColIter<'_, T> {}
+
+impl<T> FusedIterator for ColIter<'_, T> {}
+
+#[derive(Debug)]
+/// An iterator over mutable references to the elements of a single column of a DynamicMatrix
+///
+/// This struct is created by [`DynamicMatrix::col_iter_mut`](crate::DynamicMatrix::col_iter_mut).
+pub struct ColIterMut<'a, T> {
+    inner: StepBy<Skip<slice::IterMut<'a, T>>>,
+}
+
+impl<'a, T> ColIterMut<'a, T> {
+    pub(crate) fn new(data: &'a mut [T], col: usize, cols: usize) -> Self {
+        Self {
+            inner: data.iter_mut().skip(col).step_by(cols),
+        }
+    }
+}
+
+impl<'a, T> Iterator for ColIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for ColIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for ColIterMut<'_, T> {}
+
+impl<T> FusedIterator for ColIterMut<'_, T> {}
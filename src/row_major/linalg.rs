@@ -0,0 +1,678 @@
+use super::DynamicMatrix;
+use crate::errors::{matrix_error::MatrixError, shape_error::ShapeError};
+
+impl DynamicMatrix<f64> {
+    /// Computes the Frobenius inner product of two DynamicMatrix, the sum of the products of their corresponding
+    /// elements
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1.0, 2.0; 3.0, 4.0];
+    /// let b = dynamic_matrix![0.5, -1.0; 2.0, 0.25];
+    ///
+    /// assert_eq!(a.frobenius_inner(&b).unwrap(), 0.5 - 2.0 + 6.0 + 1.0);
+    /// ```
+    ///
+    /// Using matrices of different shapes will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1.0, 2.0; 3.0, 4.0];
+    /// let b = dynamic_matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    ///
+    /// a.frobenius_inner(&b).unwrap();
+    /// ```
+    pub fn frobenius_inner(&self, other: &DynamicMatrix<f64>) -> Result<f64, ShapeError> {
+        if self.shape() != other.shape() {
+            return Err(ShapeError::new(other.shape(), self.shape()));
+        }
+
+        Ok(self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(a, b)| a * b)
+            .sum())
+    }
+
+    /// Returns a new DynamicMatrix with every element replaced by the mean of the `window` elements centered on it
+    /// within its row
+    ///
+    /// Near the edges of a row the window is clamped, so the mean is taken over the elements that are available. For
+    /// an even `window` the extra element is taken from the right.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1.0, 2.0, 3.0, 4.0, 5.0; 5];
+    ///
+    /// let smooth = mat.smooth_cols(3).unwrap();
+    ///
+    /// assert_eq!(smooth.shape(), (1, 5));
+    /// assert_eq!(smooth.as_slice(), &[1.5, 2.0, 3.0, 4.0, 4.5]);
+    /// ```
+    ///
+    /// A `window` of zero will return a `ShapeError` about the window:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1.0, 2.0, 3.0, 4.0, 5.0; 5];
+    ///
+    /// assert_eq!(
+    ///     mat.smooth_cols(0).unwrap_err().to_string(),
+    ///     "The operation performed expected a window of at least 1 element but the window has 0.\n"
+    /// );
+    /// ```
+    pub fn smooth_cols(&self, window: usize) -> Result<DynamicMatrix<f64>, ShapeError> {
+        if window == 0 {
+            return Err(ShapeError::new_window_error(window));
+        }
+
+        let (before, after) = ((window - 1) / 2, window / 2);
+
+        let mut data = Vec::with_capacity(self.data.len());
+        for row in self.rows_iter() {
+            for col in 0..self.cols {
+                let start = col.saturating_sub(before);
+                let end = (col + after + 1).min(self.cols);
+                let values = &row[start..end];
+
+                data.push(values.iter().sum::<f64>() / values.len() as f64);
+            }
+        }
+
+        Ok(DynamicMatrix {
+            data,
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+
+    /// Performs the symmetric rank-k update `self += alpha * x * xᵀ` in place
+    ///
+    /// The DynamicMatrix must be square with as many rows as `x`.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let x = dynamic_matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    /// let x_t = dynamic_matrix![1.0, 4.0; 2.0, 5.0; 3.0, 6.0];
+    ///
+    /// let mut mat = dynamic_matrix![0.0, 0.0; 0.0, 0.0];
+    /// mat.syrk(0.5, &x).unwrap();
+    ///
+    /// assert_eq!(mat.as_slice(), x.matmul(&x_t).unwrap().scaled(0.5).as_slice());
+    /// ```
+    ///
+    /// Using a DynamicMatrix that isn't `x.rows() x x.rows()` will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let x = dynamic_matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    /// let mut mat = dynamic_matrix![0.0, 0.0, 0.0; 0.0, 0.0, 0.0; 0.0, 0.0, 0.0];
+    ///
+    /// mat.syrk(1.0, &x).unwrap();
+    /// ```
+    pub fn syrk(&mut self, alpha: f64, x: &DynamicMatrix<f64>) -> Result<(), ShapeError> {
+        let n = x.rows;
+        if self.shape() != (n, n) {
+            return Err(ShapeError::new(self.shape(), (n, n)));
+        }
+
+        for i in 0..n {
+            let row_i = &x.data[i * x.cols..(i + 1) * x.cols];
+            for j in 0..n {
+                let row_j = &x.data[j * x.cols..(j + 1) * x.cols];
+                let dot: f64 = row_i.iter().zip(row_j).map(|(a, b)| a * b).sum();
+
+                self.data[i * n + j] += alpha * dot;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reduces a square DynamicMatrix to upper Hessenberg form using Householder reflections
+    ///
+    /// The result is similar to the original matrix, so it has the same eigenvalues, and every element below the
+    /// first subdiagonal is zero.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![
+    ///     4.0, 1.0, -2.0, 2.0;
+    ///     1.0, 2.0, 0.0, 1.0;
+    ///     -2.0, 0.0, 3.0, -2.0;
+    ///     2.0, 1.0, -2.0, -1.0;
+    /// ];
+    ///
+    /// let h = mat.hessenberg().unwrap();
+    ///
+    /// for row in 2..4 {
+    ///     for col in 0..row - 1 {
+    ///         assert!(h[(row, col)].abs() < 1e-12);
+    ///     }
+    /// }
+    ///
+    /// // The sums of the eigenvalues and of their squares are the traces of the matrix and of its square
+    /// let trace = |m: &DynamicMatrix<f64>| (0..4).map(|i| m[(i, i)]).sum::<f64>();
+    /// assert!((trace(&h) - trace(&mat)).abs() < 1e-9);
+    /// assert!((trace(&h.matmul(&h).unwrap()) - trace(&mat.matmul(&mat).unwrap())).abs() < 1e-9);
+    /// ```
+    ///
+    /// Reducing a matrix that isn't square will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    ///
+    /// mat.hessenberg().unwrap();
+    /// ```
+    pub fn hessenberg(&self) -> Result<DynamicMatrix<f64>, ShapeError> {
+        self.ensure_square()?;
+
+        let n = self.rows;
+        let mut h = self.clone();
+        for k in 0..n.saturating_sub(2) {
+            let mut v: Vec<f64> = (k + 1..n).map(|i| h.data[i * n + k]).collect();
+
+            let norm = v.iter().map(|e| e * e).sum::<f64>().sqrt();
+            if norm == 0.0 {
+                continue;
+            }
+            v[0] += if v[0] >= 0.0 { norm } else { -norm };
+
+            let v_norm = v.iter().map(|e| e * e).sum::<f64>().sqrt();
+            for e in v.iter_mut() {
+                *e /= v_norm;
+            }
+
+            // h = (I - 2vvᵀ) h
+            for col in 0..n {
+                let dot: f64 = v
+                    .iter()
+                    .enumerate()
+                    .map(|(i, vi)| vi * h.data[(k + 1 + i) * n + col])
+                    .sum();
+                for (i, vi) in v.iter().enumerate() {
+                    h.data[(k + 1 + i) * n + col] -= 2.0 * vi * dot;
+                }
+            }
+
+            // h = h (I - 2vvᵀ)
+            for row in 0..n {
+                let dot: f64 = v
+                    .iter()
+                    .enumerate()
+                    .map(|(j, vj)| h.data[row * n + k + 1 + j] * vj)
+                    .sum();
+                for (j, vj) in v.iter().enumerate() {
+                    h.data[row * n + k + 1 + j] -= 2.0 * dot * vj;
+                }
+            }
+
+            for row in k + 2..n {
+                h.data[row * n + k] = 0.0;
+            }
+        }
+
+        Ok(h)
+    }
+
+    /// Computes the thin QR decomposition of a DynamicMatrix with at least as many rows as columns using Householder
+    /// reflections
+    ///
+    /// For a `rows x cols` matrix, `Q` is `rows x cols` with orthonormal columns and `R` is `cols x cols` and upper
+    /// triangular, such that `QR` is the original matrix.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0];
+    ///
+    /// let (q, r) = mat.qr().unwrap();
+    /// assert_eq!(q.shape(), (3, 2));
+    /// assert_eq!(r.shape(), (2, 2));
+    /// assert!(r[(1, 0)].abs() < 1e-12);
+    ///
+    /// let qr = q.matmul(&r).unwrap();
+    /// assert!(qr.iter().zip(mat.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+    ///
+    /// let qtq = q.transpose().matmul(&q).unwrap();
+    /// let identity: DynamicMatrix<f64> = DynamicMatrix::identity(2);
+    /// assert!(qtq.iter().zip(identity.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+    /// ```
+    ///
+    /// Decomposing a matrix with fewer rows than columns will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    ///
+    /// mat.qr().unwrap();
+    /// ```
+    pub fn qr(&self) -> Result<(DynamicMatrix<f64>, DynamicMatrix<f64>), ShapeError> {
+        let (m, n) = (self.rows, self.cols);
+        if m < n {
+            return Err(ShapeError::new_rows_error(m, n));
+        }
+
+        let mut r = self.clone();
+        let mut reflectors = Vec::with_capacity(n);
+        for k in 0..n {
+            let mut v: Vec<f64> = (k..m).map(|i| r.data[i * n + k]).collect();
+
+            let norm = v.iter().map(|e| e * e).sum::<f64>().sqrt();
+            if norm == 0.0 {
+                reflectors.push(Vec::new());
+                continue;
+            }
+            v[0] += if v[0] >= 0.0 { norm } else { -norm };
+
+            let v_norm = v.iter().map(|e| e * e).sum::<f64>().sqrt();
+            for e in v.iter_mut() {
+                *e /= v_norm;
+            }
+
+            // r = (I - 2vvᵀ) r
+            for col in k..n {
+                let dot: f64 = v
+                    .iter()
+                    .enumerate()
+                    .map(|(i, vi)| vi * r.data[(k + i) * n + col])
+                    .sum();
+                for (i, vi) in v.iter().enumerate() {
+                    r.data[(k + i) * n + col] -= 2.0 * vi * dot;
+                }
+            }
+
+            for row in k + 1..m {
+                r.data[row * n + k] = 0.0;
+            }
+
+            reflectors.push(v);
+        }
+
+        // Q is the product of the reflections applied to the first cols columns of the identity
+        let mut q = DynamicMatrix::from_fn((m, n), |row, col| if row == col { 1.0 } else { 0.0 });
+        for (k, v) in reflectors.iter().enumerate().rev() {
+            for col in 0..n {
+                let dot: f64 = v
+                    .iter()
+                    .enumerate()
+                    .map(|(i, vi)| vi * q.data[(k + i) * n + col])
+                    .sum();
+                for (i, vi) in v.iter().enumerate() {
+                    q.data[(k + i) * n + col] -= 2.0 * vi * dot;
+                }
+            }
+        }
+
+        r.data.truncate(n * n);
+        r.rows = n;
+
+        Ok((q, r))
+    }
+
+    /// Computes the LU decomposition of a square DynamicMatrix using Gaussian elimination with partial pivoting
+    ///
+    /// Returns the unit lower triangular `L`, the upper triangular `U` and the permutation `p` such that row `i` of
+    /// `LU` is row `p[i]` of the original matrix, that is `PA = LU`.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![2.0, 1.0, 1.0; 4.0, -6.0, 0.0; -2.0, 7.0, 2.0];
+    ///
+    /// let (l, u, p) = mat.lu().unwrap();
+    /// assert_eq!(p, vec![1, 2, 0]);
+    ///
+    /// let lu = l.matmul(&u).unwrap();
+    /// for (row, &original) in p.iter().enumerate() {
+    ///     for col in 0..3 {
+    ///         assert!((lu[(row, col)] - mat[(original, col)]).abs() < 1e-12);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Decomposing a singular DynamicMatrix will return a `MatrixError`:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1.0, 2.0, 3.0; 2.0, 4.0, 6.0; 1.0, 0.0, 1.0];
+    ///
+    /// assert_eq!(
+    ///     mat.lu().unwrap_err().to_string(),
+    ///     "The operation performed expected a non-singular matrix but the matrix is singular.\n"
+    /// );
+    /// ```
+    ///
+    /// Decomposing a matrix that isn't square will return a `MatrixError` about the shape:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    ///
+    /// mat.lu().unwrap();
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn lu(&self) -> Result<(DynamicMatrix<f64>, DynamicMatrix<f64>, Vec<usize>), MatrixError> {
+        self.ensure_square()?;
+
+        let n = self.rows;
+        let scale = self.data.iter().fold(0.0f64, |acc, e| acc.max(e.abs()));
+        let epsilon = f64::EPSILON * n as f64 * scale;
+
+        let mut u = self.clone();
+        let mut l = DynamicMatrix::zeros((n, n));
+        let mut perm: Vec<usize> = (0..n).collect();
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&a, &b| u.data[a * n + k].abs().total_cmp(&u.data[b * n + k].abs()))
+                .unwrap();
+            if u.data[pivot * n + k].abs() <= epsilon {
+                return Err(MatrixError::new_singular_error());
+            }
+            u.swap_rows(k, pivot).unwrap();
+            l.swap_rows(k, pivot).unwrap();
+            perm.swap(k, pivot);
+
+            for row in k + 1..n {
+                let factor = u.data[row * n + k] / u.data[k * n + k];
+                l.data[row * n + k] = factor;
+                for col in k..n {
+                    u.data[row * n + col] -= factor * u.data[k * n + col];
+                }
+                u.data[row * n + k] = 0.0;
+            }
+        }
+
+        for i in 0..n {
+            l.data[i * n + i] = 1.0;
+        }
+
+        Ok((l, u, perm))
+    }
+
+    /// Computes the Cholesky factorization of a symmetric positive definite DynamicMatrix, the lower triangular `L`
+    /// such that `LLᵀ` is the original matrix
+    ///
+    /// Only the lower triangle of the DynamicMatrix is read, the upper triangle is assumed to mirror it.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![4.0, 12.0, -16.0; 12.0, 37.0, -43.0; -16.0, -43.0, 98.0];
+    ///
+    /// let l = mat.cholesky().unwrap();
+    /// assert_eq!(l.as_slice(), &[2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0]);
+    ///
+    /// let llt = l.matmul(&l.transpose()).unwrap();
+    /// assert!(llt.iter().zip(mat.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+    /// ```
+    ///
+    /// Factorizing a DynamicMatrix that is not positive definite will return a `MatrixError`:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1.0, 2.0; 2.0, 1.0];
+    ///
+    /// assert_eq!(
+    ///     mat.cholesky().unwrap_err().to_string(),
+    ///     "The operation performed expected a positive definite matrix but the matrix is not.\n"
+    /// );
+    /// ```
+    ///
+    /// Factorizing a matrix that isn't square will return a `MatrixError` about the shape:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    ///
+    /// mat.cholesky().unwrap();
+    /// ```
+    pub fn cholesky(&self) -> Result<DynamicMatrix<f64>, MatrixError> {
+        self.ensure_square()?;
+
+        let n = self.rows;
+        let mut l = DynamicMatrix::zeros((n, n));
+        for row in 0..n {
+            for col in 0..=row {
+                let dot: f64 = (0..col)
+                    .map(|k| l.data[row * n + k] * l.data[col * n + k])
+                    .sum();
+                let e = self.data[row * n + col] - dot;
+
+                if row == col {
+                    if e <= 0.0 || !e.is_finite() {
+                        return Err(MatrixError::new_positive_definite_error());
+                    }
+                    l.data[row * n + col] = e.sqrt();
+                } else {
+                    l.data[row * n + col] = e / l.data[col * n + col];
+                }
+            }
+        }
+
+        Ok(l)
+    }
+
+    /// Estimates the spectral norm, the largest singular value, of the DynamicMatrix by running `iters` iterations of
+    /// power iteration on `AᵀA`
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1.0, 0.0, 0.0; 0.0, -3.0, 0.0; 0.0, 0.0, 2.0];
+    ///
+    /// assert!((mat.spectral_norm(50) - 3.0).abs() < 1e-6);
+    /// ```
+    pub fn spectral_norm(&self, iters: usize) -> f64 {
+        let norm = |v: &[f64]| v.iter().map(|e| e * e).sum::<f64>().sqrt();
+
+        let mut v = vec![1.0 / (self.cols as f64).sqrt(); self.cols];
+        for _ in 0..iters {
+            let av = self.mul_vec(&v);
+
+            let mut atav = vec![0.0; self.cols];
+            for (row, a) in self.rows_iter().zip(av.iter()) {
+                for (e, r) in atav.iter_mut().zip(row) {
+                    *e += r * a;
+                }
+            }
+
+            let atav_norm = norm(&atav);
+            if atav_norm == 0.0 {
+                return 0.0;
+            }
+            v = atav.into_iter().map(|e| e / atav_norm).collect();
+        }
+
+        norm(&self.mul_vec(&v))
+    }
+
+    /// Computes the standard deviation of every column of the DynamicMatrix
+    ///
+    /// The squared deviations are divided by `rows() - ddof`, so a `ddof` of `0` gives the population standard
+    /// deviation and a `ddof` of `1` gives the sample standard deviation. Columns with no more than `ddof` elements
+    /// have no standard deviation and give `NaN`.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1.0, 2.0; 3.0, 2.0; 5.0, 2.0];
+    ///
+    /// let population = mat.std_cols(0);
+    /// assert!((population[0] - (8.0f64 / 3.0).sqrt()).abs() < 1e-12);
+    /// assert_eq!(population[1], 0.0);
+    ///
+    /// let sample = mat.std_cols(1);
+    /// assert!((sample[0] - 2.0).abs() < 1e-12);
+    /// assert_eq!(sample[1], 0.0);
+    ///
+    /// let single = dynamic_matrix![1.0, 2.0];
+    /// assert!(single.std_cols(1).iter().all(|e| e.is_nan()));
+    /// ```
+    pub fn std_cols(&self, ddof: usize) -> Vec<f64> {
+        self.cols_iter()
+            .map(|col| {
+                if self.rows <= ddof {
+                    return f64::NAN;
+                }
+
+                let values: Vec<f64> = col.copied().collect();
+                let mean = values.iter().sum::<f64>() / self.rows as f64;
+                let squares: f64 = values.iter().map(|e| (e - mean) * (e - mean)).sum();
+
+                (squares / (self.rows - ddof) as f64).sqrt()
+            })
+            .collect()
+    }
+
+    /// Returns a basis of the row space of the DynamicMatrix, the non-zero rows of its reduced row echelon form
+    ///
+    /// Pivots with an absolute value no greater than `epsilon` are treated as zero.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1.0, 2.0, 3.0; 2.0, 4.0, 6.0; 1.0, 0.0, 1.0];
+    ///
+    /// let basis = mat.row_space(1e-10);
+    /// assert_eq!(basis.len(), 2);
+    /// assert_eq!(basis[0], vec![1.0, 0.0, 1.0]);
+    /// assert_eq!(basis[1], vec![0.0, 1.0, 1.0]);
+    ///
+    /// let zero: DynamicMatrix<f64> = DynamicMatrix::zeros((2, 3));
+    /// assert!(zero.row_space(1e-10).is_empty());
+    /// ```
+    pub fn row_space(&self, epsilon: f64) -> Vec<Vec<f64>> {
+        let (reduced, pivots) = self.rref(epsilon);
+
+        reduced
+            .rows_iter()
+            .take(pivots.len())
+            .map(|row| row.to_vec())
+            .collect()
+    }
+
+    /// Returns a basis of the column space of the DynamicMatrix, the original columns at the pivot positions of its
+    /// reduced row echelon form
+    ///
+    /// Pivots with an absolute value no greater than `epsilon` are treated as zero.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1.0, 2.0, 0.0, 3.0; 2.0, 4.0, 1.0, 7.0; 3.0, 6.0, 1.0, 10.0];
+    ///
+    /// let basis = mat.column_space(1e-10);
+    /// assert_eq!(basis.len(), 2);
+    /// assert_eq!(basis[0], vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(basis[1], vec![0.0, 1.0, 1.0]);
+    ///
+    /// let zero: DynamicMatrix<f64> = DynamicMatrix::zeros((2, 3));
+    /// assert!(zero.column_space(1e-10).is_empty());
+    /// ```
+    pub fn column_space(&self, epsilon: f64) -> Vec<Vec<f64>> {
+        let (_, pivots) = self.rref(epsilon);
+
+        pivots
+            .into_iter()
+            .map(|col| self.col_iter(col).copied().collect())
+            .collect()
+    }
+
+    /// Solves the overdetermined system `A x = b` in the least-squares sense by solving the normal equations
+    /// `Aᵀ A x = Aᵀ b`
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// // Noisy samples of y = 2x + 1
+    /// let xs = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let ys = [1.1, 2.9, 5.2, 6.8, 9.1, 10.9];
+    ///
+    /// let a = DynamicMatrix::try_from_rows(xs.iter().map(|&x| vec![x, 1.0])).unwrap();
+    /// let fit = a.lstsq(&ys).unwrap();
+    ///
+    /// assert!((fit[0] - 2.0).abs() < 0.1);
+    /// assert!((fit[1] - 1.0).abs() < 0.2);
+    /// ```
+    ///
+    /// Using a right-hand side whose length is not `rows()` will return a `MatrixError` about the shape:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1.0, 0.0; 0.0, 1.0; 1.0, 1.0];
+    ///
+    /// a.lstsq(&[1.0, 2.0]).unwrap();
+    /// ```
+    ///
+    /// Using a DynamicMatrix without full column rank will return a `MatrixError` about the rank:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1.0, 2.0; 2.0, 4.0; 3.0, 6.0];
+    ///
+    /// assert_eq!(
+    ///     a.lstsq(&[1.0, 2.0, 3.0]).unwrap_err().to_string(),
+    ///     "The operation performed expected full column rank 2 but the matrix has rank 1.\n"
+    /// );
+    /// ```
+    pub fn lstsq(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        if b.len() != self.rows {
+            return Err(ShapeError::new_len_error(b.len(), self.rows).into());
+        }
+
+        let n = self.cols;
+        let at = self.transpose();
+        let mut normal = DynamicMatrix::zeros((n, n));
+        normal.syrk(1.0, &at)?;
+        normal.push_col(at.mul_vec(b))?;
+
+        let scale = normal.data.iter().fold(0.0f64, |acc, e| acc.max(e.abs()));
+        let epsilon = f64::EPSILON * self.rows.max(n) as f64 * scale;
+        let (reduced, pivots) = normal.rref(epsilon);
+
+        let rank = pivots.iter().take_while(|&&col| col < n).count();
+        if rank < n {
+            return Err(MatrixError::new_rank_error(rank, n));
+        }
+
+        Ok(reduced.col_iter(n).copied().collect())
+    }
+
+    /// Computes the reduced row echelon form by Gauss-Jordan elimination with partial pivoting, along with the
+    /// indices of the pivot columns
+    fn rref(&self, epsilon: f64) -> (DynamicMatrix<f64>, Vec<usize>) {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut r = self.clone();
+        let mut pivots = Vec::new();
+        for col in 0..cols {
+            let top = pivots.len();
+            if top == rows {
+                break;
+            }
+
+            let pivot = (top..rows)
+                .max_by(|&a, &b| {
+                    r.data[a * cols + col]
+                        .abs()
+                        .total_cmp(&r.data[b * cols + col].abs())
+                })
+                .unwrap();
+            if r.data[pivot * cols + col].abs() <= epsilon {
+                for row in top..rows {
+                    r.data[row * cols + col] = 0.0;
+                }
+                continue;
+            }
+            r.swap_rows(top, pivot).unwrap();
+
+            let scale = r.data[top * cols + col];
+            for e in &mut r.data[top * cols..(top + 1) * cols] {
+                *e /= scale;
+            }
+            for row in (0..rows).filter(|&row| row != top) {
+                let factor = r.data[row * cols + col];
+                if factor != 0.0 {
+                    for c in 0..cols {
+                        r.data[row * cols + c] -= factor * r.data[top * cols + c];
+                    }
+                }
+                r.data[row * cols + col] = 0.0;
+            }
+
+            pivots.push(col);
+        }
+
+        (r, pivots)
+    }
+
+    /// Multiplies the DynamicMatrix with a column vector of length `cols()`
+    fn mul_vec(&self, v: &[f64]) -> Vec<f64> {
+        self.rows_iter()
+            .map(|row| row.iter().zip(v).map(|(a, b)| a * b).sum())
+            .collect()
+    }
+}
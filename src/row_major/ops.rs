@@ -0,0 +1,1086 @@
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    hash::Hash,
+    ops::{Add, Mul, MulAssign, Sub},
+};
+
+use super::DynamicMatrix;
+use crate::errors::{indexing_error::IndexingError, shape_error::ShapeError};
+
+impl<T> DynamicMatrix<T> {
+    /// Sums the elements of the DynamicMatrix along the given axis and returns the sums as a DynamicMatrix
+    ///
+    /// Summing along axis `0` sums down each column and returns a `1 x cols` matrix, while summing along axis `1`
+    /// sums across each row and returns a `rows x 1` matrix.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let col_sums = mat.sum_axis(0).unwrap();
+    /// assert_eq!(col_sums.shape(), (1, 3));
+    /// assert_eq!(col_sums.as_slice(), &[5, 7, 9]);
+    ///
+    /// let row_sums = mat.sum_axis(1).unwrap();
+    /// assert_eq!(row_sums.shape(), (2, 1));
+    /// assert_eq!(row_sums.as_slice(), &[6, 15]);
+    /// ```
+    ///
+    /// Summing along any other axis will return an `IndexingError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.sum_axis(2).unwrap();
+    /// ```
+    pub fn sum_axis(&self, axis: usize) -> Result<DynamicMatrix<T>, IndexingError>
+    where
+        T: Add<Output = T> + Copy + Default,
+    {
+        match axis {
+            0 => {
+                let mut sums = vec![T::default(); self.cols];
+                for (i, &e) in self.data.iter().enumerate() {
+                    sums[i % self.cols] = sums[i % self.cols] + e;
+                }
+
+                Ok(DynamicMatrix {
+                    data: sums,
+                    rows: 1,
+                    cols: self.cols,
+                })
+            }
+            1 => {
+                let sums = (0..self.rows)
+                    .map(|row| {
+                        self.data[row * self.cols..(row + 1) * self.cols]
+                            .iter()
+                            .fold(T::default(), |acc, &e| acc + e)
+                    })
+                    .collect();
+
+                Ok(DynamicMatrix {
+                    data: sums,
+                    rows: self.rows,
+                    cols: 1,
+                })
+            }
+            _ => Err(IndexingError::new_axis_error(axis, 2)),
+        }
+    }
+
+    /// Returns a new DynamicMatrix with every column multiplied by the corresponding factor
+    ///
+    /// This is equivalent to right-multiplying by a diagonal matrix with `factors` on its diagonal.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let scaled = mat.scale_cols_by(&[1, 2, 3]).unwrap();
+    ///
+    /// assert_eq!(scaled.shape(), (2, 3));
+    /// assert_eq!(scaled.as_slice(), &[1, 4, 9, 4, 10, 18]);
+    /// ```
+    ///
+    /// Providing a number of factors unequal to the number of columns will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.scale_cols_by(&[1, 2]).unwrap();
+    /// ```
+    pub fn scale_cols_by(&self, factors: &[T]) -> Result<DynamicMatrix<T>, ShapeError>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        if factors.len() != self.cols {
+            return Err(ShapeError::new_cols_error(self.cols, factors.len()));
+        }
+
+        Ok(DynamicMatrix {
+            data: self
+                .data
+                .iter()
+                .enumerate()
+                .map(|(i, &e)| e * factors[i % self.cols])
+                .collect(),
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+
+    /// Returns a new DynamicMatrix with every row multiplied by the corresponding factor
+    ///
+    /// This is equivalent to left-multiplying by a diagonal matrix with `factors` on its diagonal.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2; 3, 4; 5, 6];
+    ///
+    /// let scaled = mat.scale_rows_by(&[1, 2, 3]).unwrap();
+    ///
+    /// assert_eq!(scaled.shape(), (3, 2));
+    /// assert_eq!(scaled.as_slice(), &[1, 2, 6, 8, 15, 18]);
+    /// ```
+    ///
+    /// Providing a number of factors unequal to the number of rows will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2; 3, 4; 5, 6];
+    ///
+    /// mat.scale_rows_by(&[1, 2]).unwrap();
+    /// ```
+    pub fn scale_rows_by(&self, factors: &[T]) -> Result<DynamicMatrix<T>, ShapeError>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        if factors.len() != self.rows {
+            return Err(ShapeError::new_rows_error(self.rows, factors.len()));
+        }
+
+        Ok(DynamicMatrix {
+            data: self
+                .rows_iter()
+                .zip(factors)
+                .flat_map(|(row, &factor)| row.iter().map(move |&e| e * factor))
+                .collect(),
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+
+    /// Adds two DynamicMatrix element-wise and returns the result as a new DynamicMatrix
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// let b = dynamic_matrix![6, 5, 4; 3, 2, 1];
+    ///
+    /// let sum = a.checked_add(&b).unwrap();
+    ///
+    /// assert_eq!(sum.shape(), (2, 3));
+    /// assert_eq!(sum.as_slice(), &[7, 7, 7, 7, 7, 7]);
+    /// ```
+    ///
+    /// Adding matrices of different shapes will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// let b = dynamic_matrix![1, 2; 3, 4];
+    ///
+    /// a.checked_add(&b).unwrap();
+    /// ```
+    pub fn checked_add(&self, other: &DynamicMatrix<T>) -> Result<DynamicMatrix<T>, ShapeError>
+    where
+        T: Add<Output = T> + Clone,
+    {
+        if self.shape() != other.shape() {
+            return Err(ShapeError::new(other.shape(), self.shape()));
+        }
+
+        Ok(DynamicMatrix {
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(a, b)| a.clone() + b.clone())
+                .collect(),
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+
+    /// Multiplies every element of the DynamicMatrix by a scalar in place
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.scale(2);
+    ///
+    /// assert_eq!(mat.as_slice(), &[2, 4, 6, 8, 10, 12]);
+    ///
+    /// let mut empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// empty.scale(2);
+    /// assert_eq!(empty.shape(), (0, 3));
+    /// ```
+    pub fn scale(&mut self, factor: T)
+    where
+        T: Mul<Output = T> + Clone,
+    {
+        for e in self.data.iter_mut() {
+            *e = e.clone() * factor.clone();
+        }
+    }
+
+    /// Returns a new DynamicMatrix with every element multiplied by a scalar
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let scaled = mat.scaled(2);
+    ///
+    /// assert_eq!(scaled.as_slice(), &[2, 4, 6, 8, 10, 12]);
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn scaled(&self, factor: T) -> DynamicMatrix<T>
+    where
+        T: Mul<Output = T> + Clone,
+    {
+        DynamicMatrix {
+            data: self
+                .data
+                .iter()
+                .map(|e| e.clone() * factor.clone())
+                .collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Counts the number of positions at which two DynamicMatrix hold equal elements
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let expected = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    /// let predicted = dynamic_matrix![1, 0, 3; 4, 5, 0; 0, 8, 9];
+    ///
+    /// assert_eq!(expected.matching_count(&predicted).unwrap(), 6);
+    /// ```
+    ///
+    /// Comparing matrices of different shapes will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// let b = dynamic_matrix![1, 2; 4, 5];
+    ///
+    /// a.matching_count(&b).unwrap();
+    /// ```
+    pub fn matching_count(&self, other: &DynamicMatrix<T>) -> Result<usize, ShapeError>
+    where
+        T: PartialEq,
+    {
+        if self.shape() != other.shape() {
+            return Err(ShapeError::new(other.shape(), self.shape()));
+        }
+
+        Ok(self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .filter(|(a, b)| a == b)
+            .count())
+    }
+
+    /// Multiplies two DynamicMatrix and returns the product as a new DynamicMatrix
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// let b = dynamic_matrix![7, 8; 9, 10; 11, 12];
+    ///
+    /// let product = a.matmul(&b).unwrap();
+    ///
+    /// assert_eq!(product.shape(), (2, 2));
+    /// assert_eq!(product.as_slice(), &[58, 64, 139, 154]);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert_eq!(empty.matmul(&b).unwrap().shape(), (0, 2));
+    /// ```
+    ///
+    /// Multiplying when the number of columns of the left matrix is unequal to the number of rows of the right
+    /// matrix will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// let b = dynamic_matrix![1, 2; 3, 4];
+    ///
+    /// a.matmul(&b).unwrap();
+    /// ```
+    pub fn matmul(&self, other: &DynamicMatrix<T>) -> Result<DynamicMatrix<T>, ShapeError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Clone + Default,
+    {
+        if self.cols != other.rows {
+            return Err(ShapeError::new_rows_error(other.rows, self.cols));
+        }
+
+        let (rows, cols) = (self.rows, other.cols);
+        let mut data = vec![T::default(); rows * cols];
+        for i in 0..rows {
+            for k in 0..self.cols {
+                let a = &self.data[i * self.cols + k];
+                for j in 0..cols {
+                    let out = &mut data[i * cols + j];
+                    *out = out.clone() + a.clone() * other.data[k * cols + j].clone();
+                }
+            }
+        }
+
+        Ok(DynamicMatrix { data, rows, cols })
+    }
+
+    /// Computes `self * diag(weights) * other` without building the diagonal matrix
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// let b = dynamic_matrix![7, 8; 9, 10; 11, 12];
+    /// let weights = [2, -1, 3];
+    ///
+    /// let mut diag: DynamicMatrix<isize> = DynamicMatrix::zeros((3, 3));
+    /// for (i, &w) in weights.iter().enumerate() {
+    ///     diag[(i, i)] = w;
+    /// }
+    /// let expected = a.matmul(&diag).unwrap().matmul(&b).unwrap();
+    ///
+    /// let product = a.weighted_matmul(&weights, &b).unwrap();
+    ///
+    /// assert_eq!(product.shape(), (2, 2));
+    /// assert_eq!(product.as_slice(), expected.as_slice());
+    /// ```
+    ///
+    /// Passing a number of weights unequal to the number of columns of the left matrix will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    /// let b = dynamic_matrix![7, 8; 9, 10; 11, 12];
+    ///
+    /// a.weighted_matmul(&[1, 2], &b).unwrap();
+    /// ```
+    pub fn weighted_matmul(
+        &self,
+        weights: &[T],
+        other: &DynamicMatrix<T>,
+    ) -> Result<DynamicMatrix<T>, ShapeError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Copy + Default,
+    {
+        if weights.len() != self.cols {
+            return Err(ShapeError::new_cols_error(weights.len(), self.cols));
+        }
+        if self.cols != other.rows {
+            return Err(ShapeError::new_rows_error(other.rows, self.cols));
+        }
+
+        let (rows, cols) = (self.rows, other.cols);
+        let mut data = vec![T::default(); rows * cols];
+        for i in 0..rows {
+            for (k, &w) in weights.iter().enumerate() {
+                let a = self.data[i * self.cols + k] * w;
+                for j in 0..cols {
+                    let out = &mut data[i * cols + j];
+                    *out = *out + a * other.data[k * cols + j];
+                }
+            }
+        }
+
+        Ok(DynamicMatrix { data, rows, cols })
+    }
+
+    /// Replaces every element with the running product of the elements up to it in its row, from left to right
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 2, 2, 2];
+    ///
+    /// mat.cumprod_rows();
+    ///
+    /// assert_eq!(mat.as_slice(), &[1, 2, 6, 2, 4, 8]);
+    /// ```
+    pub fn cumprod_rows(&mut self)
+    where
+        T: MulAssign + Copy,
+    {
+        for row in self.rows_iter_mut() {
+            for col in 1..row.len() {
+                let prev = row[col - 1];
+                row[col] *= prev;
+            }
+        }
+    }
+
+    /// Replaces every element with the running product of the elements up to it in its column, from top to bottom
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 2, 2, 2];
+    ///
+    /// mat.cumprod_cols();
+    ///
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 2, 4, 6]);
+    /// ```
+    pub fn cumprod_cols(&mut self)
+    where
+        T: MulAssign + Copy,
+    {
+        for i in self.cols..self.data.len() {
+            let prev = self.data[i - self.cols];
+            self.data[i] *= prev;
+        }
+    }
+
+    /// Returns `true` if all the elements of the DynamicMatrix are equal, which is vacuously the case for an empty
+    /// DynamicMatrix
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// assert!(dynamic_matrix![7, 7; 7, 7].is_uniform());
+    /// assert!(!dynamic_matrix![7, 7; 7, 8].is_uniform());
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(3);
+    /// assert!(empty.is_uniform());
+    /// ```
+    pub fn is_uniform(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.data.first() {
+            Some(first) => self.data.iter().all(|e| e == first),
+            None => true,
+        }
+    }
+
+    /// Counts how many times every distinct element occurs in the DynamicMatrix
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let labels = dynamic_matrix![0, 1, 1; 2, 1, 0; 0, 0, 1];
+    ///
+    /// let counts = labels.value_counts();
+    ///
+    /// assert_eq!(counts.len(), 3);
+    /// assert_eq!(counts[&0], 4);
+    /// assert_eq!(counts[&1], 4);
+    /// assert_eq!(counts[&2], 1);
+    /// ```
+    pub fn value_counts(&self) -> HashMap<T, usize>
+    where
+        T: Eq + Hash + Clone,
+    {
+        let mut counts = HashMap::new();
+        for e in self.data.iter() {
+            *counts.entry(e.clone()).or_insert(0) += 1;
+        }
+
+        counts
+    }
+
+    /// Keeps the lower triangle, including the main diagonal, and sets every element above the main diagonal to
+    /// `zero` in place
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// mat.tril_mut(0);
+    ///
+    /// assert_eq!(mat.as_slice(), &[1, 0, 0, 4, 5, 0, 7, 8, 9]);
+    /// ```
+    pub fn tril_mut(&mut self, zero: T)
+    where
+        T: Clone,
+    {
+        for ((row, col), e) in self.indexed_iter_mut() {
+            if col > row {
+                *e = zero.clone();
+            }
+        }
+    }
+
+    /// Keeps the upper triangle, including the main diagonal, and sets every element below the main diagonal to
+    /// `zero` in place
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mut mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// mat.triu_mut(0);
+    ///
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 0, 5, 6, 0, 0, 9]);
+    /// ```
+    pub fn triu_mut(&mut self, zero: T)
+    where
+        T: Clone,
+    {
+        for ((row, col), e) in self.indexed_iter_mut() {
+            if col < row {
+                *e = zero.clone();
+            }
+        }
+    }
+
+    /// Computes the Kronecker sum `A ⊗ I_m + I_n ⊗ B` of a square `n x n` DynamicMatrix `A` and a square `m x m`
+    /// DynamicMatrix `B`
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1, 2; 3, 4];
+    /// let b = dynamic_matrix![5, 6; 7, 8];
+    ///
+    /// let sum = a.kron_sum(&b).unwrap();
+    ///
+    /// assert_eq!(sum.shape(), (4, 4));
+    /// assert_eq!(
+    ///     sum.as_slice(),
+    ///     &[
+    ///         6, 6, 2, 0,
+    ///         7, 9, 0, 2,
+    ///         3, 0, 9, 6,
+    ///         0, 3, 7, 12,
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// Using a matrix that isn't square will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1, 2; 3, 4];
+    /// let b = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// a.kron_sum(&b).unwrap();
+    /// ```
+    pub fn kron_sum(&self, other: &DynamicMatrix<T>) -> Result<DynamicMatrix<T>, ShapeError>
+    where
+        T: Add<Output = T> + Copy + Default,
+    {
+        self.ensure_square()?;
+        other.ensure_square()?;
+
+        let (n, m) = (self.rows, other.rows);
+        let size = n * m;
+        let mut data = vec![T::default(); size * size];
+        for i in 0..n {
+            for j in 0..n {
+                for k in 0..m {
+                    for l in 0..m {
+                        let mut e = T::default();
+                        if k == l {
+                            e = e + self.data[i * n + j];
+                        }
+                        if i == j {
+                            e = e + other.data[k * m + l];
+                        }
+
+                        data[(i * m + k) * size + j * m + l] = e;
+                    }
+                }
+            }
+        }
+
+        Ok(DynamicMatrix {
+            data,
+            rows: size,
+            cols: size,
+        })
+    }
+
+    /// Finds the column of the maximum element in every window of `window` consecutive elements in every row
+    ///
+    /// The result has `cols() - window + 1` columns, one for each position of the window, and the first of several
+    /// equal maxima is taken.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 3, 2, 5, 4; 5];
+    ///
+    /// let argmax = mat.rolling_argmax_rows(3).unwrap();
+    ///
+    /// assert_eq!(argmax.shape(), (1, 3));
+    /// assert_eq!(argmax.as_slice(), &[1, 3, 3]);
+    /// ```
+    ///
+    /// Using a window that is empty or larger than the number of columns will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 3, 2, 5, 4; 5];
+    ///
+    /// mat.rolling_argmax_rows(6).unwrap();
+    /// ```
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 3, 2, 5, 4; 5];
+    ///
+    /// assert_eq!(
+    ///     mat.rolling_argmax_rows(0).unwrap_err().to_string(),
+    ///     "The operation performed expected a window of at least 1 element but the window has 0.\n"
+    /// );
+    /// ```
+    pub fn rolling_argmax_rows(&self, window: usize) -> Result<DynamicMatrix<usize>, ShapeError>
+    where
+        T: PartialOrd,
+    {
+        if window == 0 {
+            return Err(ShapeError::new_window_error(window));
+        }
+        if window > self.cols {
+            return Err(ShapeError::new_cols_error(self.cols, window));
+        }
+
+        let cols = self.cols - window + 1;
+        let mut data = Vec::with_capacity(self.rows * cols);
+        for row in self.rows_iter() {
+            for start in 0..cols {
+                let mut max = start;
+                for col in start + 1..start + window {
+                    if row[col] > row[max] {
+                        max = col;
+                    }
+                }
+
+                data.push(max);
+            }
+        }
+
+        Ok(DynamicMatrix {
+            data,
+            rows: self.rows,
+            cols,
+        })
+    }
+
+    /// Replaces every element with its 0-based ascending rank within its column, ties keeping their row order
+    ///
+    /// Values that can't be compared with themselves, such as NaN, rank after every other value in their column.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![3.0, 1.0; 1.0, 2.0; 2.0, 1.0];
+    ///
+    /// let ranks = mat.column_ranks();
+    ///
+    /// assert_eq!(ranks.shape(), (3, 2));
+    /// assert_eq!(ranks.get_col(0).unwrap(), vec![&2, &0, &1]);
+    /// assert_eq!(ranks.get_col(1).unwrap(), vec![&0, &2, &1]);
+    ///
+    /// let with_nan = dynamic_matrix![f64::NAN; 3.0; f64::NAN; -1.0; 2.0];
+    /// assert_eq!(with_nan.column_ranks().as_slice(), &[3, 2, 4, 0, 1]);
+    /// ```
+    pub fn column_ranks(&self) -> DynamicMatrix<usize>
+    where
+        T: PartialOrd,
+    {
+        let mut data = vec![0; self.data.len()];
+        let mut order: Vec<usize> = Vec::with_capacity(self.rows);
+        for col in 0..self.cols {
+            order.clear();
+            order.extend(0..self.rows);
+            order.sort_by(|&a, &b| {
+                let (a, b) = (
+                    &self.data[a * self.cols + col],
+                    &self.data[b * self.cols + col],
+                );
+                // Values that can't be compared with themselves, such as NaN, are greater than every other value
+                match (a.partial_cmp(a).is_none(), b.partial_cmp(b).is_none()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) => Ordering::Greater,
+                    (false, true) => Ordering::Less,
+                    (false, false) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+                }
+            });
+
+            for (rank, &row) in order.iter().enumerate() {
+                data[row * self.cols + col] = rank;
+            }
+        }
+
+        DynamicMatrix {
+            data,
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Computes the permanent of a square DynamicMatrix by dynamic programming over the subsets of its columns
+    ///
+    /// The running time and memory are exponential in the number of rows, so this is only meant for small matrices.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// // 1*(5*9 + 6*8) + 2*(4*9 + 6*7) + 3*(4*8 + 5*7)
+    /// assert_eq!(mat.permanent().unwrap(), 450);
+    /// assert_eq!(DynamicMatrix::new([[7]]).permanent().unwrap(), 7);
+    ///
+    /// // Every one of the 4! permutations contributes a product of one
+    /// assert_eq!(DynamicMatrix::filled((4, 4), 1).permanent().unwrap(), 24);
+    /// ```
+    ///
+    /// Computing the permanent of a non-square DynamicMatrix will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.permanent().unwrap();
+    /// ```
+    ///
+    /// The permanent of a DynamicMatrix without rows would be one, which can't be expressed with these bounds, so it
+    /// will return a `ShapeError`:
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(0);
+    ///
+    /// assert_eq!(
+    ///     empty.permanent().unwrap_err().to_string(),
+    ///     "The operation performed expected at least 1 rows but the matrix has 0.\n"
+    /// );
+    /// ```
+    pub fn permanent(&self) -> Result<T, ShapeError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Copy + Default,
+    {
+        self.ensure_square()?;
+
+        let n = self.rows;
+        if n == 0 {
+            return Err(ShapeError::new_min_rows_error(n, 1));
+        }
+
+        // sums[cols] is the permanent of the minor made of the first |cols| rows and the columns in the subset cols
+        let mut sums = vec![T::default(); 1 << n];
+        for col in 0..n {
+            sums[1 << col] = self.data[col];
+        }
+        for subset in 1usize..1 << n {
+            let row = subset.count_ones() as usize - 1;
+            if row == 0 {
+                continue;
+            }
+
+            let mut sum = T::default();
+            for col in (0..n).filter(|&col| subset & (1 << col) != 0) {
+                sum = sum + sums[subset ^ (1 << col)] * self.data[row * n + col];
+            }
+            sums[subset] = sum;
+        }
+
+        Ok(sums[(1 << n) - 1])
+    }
+
+    /// Checks if two DynamicMatrix can be broadcast against each other, that is if every dimension is either equal
+    /// or 1 in one of them
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// assert!(mat.broadcast_compatible(&dynamic_matrix![0, 0, 0; 0, 0, 0]));
+    /// assert!(mat.broadcast_compatible(&dynamic_matrix![10, 20, 30]));
+    /// assert!(mat.broadcast_compatible(&DynamicMatrix::new([[1], [2]])));
+    /// assert!(!mat.broadcast_compatible(&dynamic_matrix![1, 2; 3, 4]));
+    /// ```
+    pub fn broadcast_compatible(&self, other: &DynamicMatrix<T>) -> bool {
+        let compatible = |a: usize, b: usize| a == b || a == 1 || b == 1;
+
+        compatible(self.rows, other.rows) && compatible(self.cols, other.cols)
+    }
+
+    /// Adds two DynamicMatrix after stretching every dimension of length 1 to match the other one, following the
+    /// NumPy broadcasting rules
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// let sum = mat.broadcast_add(&dynamic_matrix![10, 20, 30]).unwrap();
+    /// assert_eq!(sum.shape(), (2, 3));
+    /// assert_eq!(sum.as_slice(), &[11, 22, 33, 14, 25, 36]);
+    ///
+    /// let sum = mat.broadcast_add(&DynamicMatrix::new([[100], [200]])).unwrap();
+    /// assert_eq!(sum.shape(), (2, 3));
+    /// assert_eq!(sum.as_slice(), &[101, 102, 103, 204, 205, 206]);
+    ///
+    /// let outer = dynamic_matrix![1, 2].broadcast_add(&DynamicMatrix::new([[10], [20]])).unwrap();
+    /// assert_eq!(outer.as_slice(), &[11, 12, 21, 22]);
+    /// ```
+    ///
+    /// Adding DynamicMatrix that are not broadcast compatible will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.broadcast_add(&dynamic_matrix![1, 2; 3, 4]).unwrap();
+    /// ```
+    pub fn broadcast_add(&self, other: &DynamicMatrix<T>) -> Result<DynamicMatrix<T>, ShapeError>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        if !self.broadcast_compatible(other) {
+            return Err(ShapeError::new(other.shape(), self.shape()));
+        }
+
+        let broadcast = |a: usize, b: usize| if a == 1 { b } else { a };
+        let rows = broadcast(self.rows, other.rows);
+        let cols = broadcast(self.cols, other.cols);
+
+        // A dimension of length 1 is stretched by always reading its only index
+        let index = |mat: &DynamicMatrix<T>, row: usize, col: usize| {
+            let row = if mat.rows == 1 { 0 } else { row };
+            let col = if mat.cols == 1 { 0 } else { col };
+            row * mat.cols + col
+        };
+
+        let mut data = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                data.push(self.data[index(self, row, col)] + other.data[index(other, row, col)]);
+            }
+        }
+
+        Ok(DynamicMatrix { data, rows, cols })
+    }
+
+    /// Computes the determinant of a square DynamicMatrix by cofactor expansion along the first row, without any
+    /// division so that it also works for integer elements
+    ///
+    /// The running time grows factorially with the number of rows, so this is only meant for small matrices.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![2, -3, 1; 2, 0, -1; 1, 4, 5];
+    ///
+    /// assert_eq!(mat.determinant_generic().unwrap(), 49);
+    /// assert_eq!(DynamicMatrix::new([[7]]).determinant_generic().unwrap(), 7);
+    /// ```
+    ///
+    /// Computing the determinant of a non-square DynamicMatrix will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.determinant_generic().unwrap();
+    /// ```
+    ///
+    /// The determinant of a DynamicMatrix without rows would be one, which can't be expressed with these bounds, so it
+    /// will return a `ShapeError`:
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(0);
+    ///
+    /// assert_eq!(
+    ///     empty.determinant_generic().unwrap_err().to_string(),
+    ///     "The operation performed expected at least 1 rows but the matrix has 0.\n"
+    /// );
+    /// ```
+    pub fn determinant_generic(&self) -> Result<T, ShapeError>
+    where
+        T: Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Copy + Default,
+    {
+        self.ensure_square()?;
+
+        if self.rows == 0 {
+            return Err(ShapeError::new_min_rows_error(self.rows, 1));
+        }
+
+        let indices: Vec<usize> = (0..self.rows).collect();
+        Ok(self.cofactor_expansion(&indices, &indices))
+    }
+
+    /// Computes the adjugate of a square DynamicMatrix, the transpose of its matrix of cofactors
+    ///
+    /// The cofactors are computed by cofactor expansion, so this is only meant for small matrices.
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![2, -3, 1; 2, 0, -1; 1, 4, 5];
+    ///
+    /// let adj = mat.adjugate().unwrap();
+    /// assert_eq!(adj.as_slice(), &[4, 19, 3, -11, 9, 4, 8, -11, 6]);
+    ///
+    /// let det = mat.determinant_generic().unwrap();
+    /// let mut expected: DynamicMatrix<isize> = DynamicMatrix::identity(3);
+    /// expected.scale(det);
+    /// assert_eq!(mat.matmul(&adj).unwrap().as_slice(), expected.as_slice());
+    /// ```
+    ///
+    /// Computing the adjugate of a non-square DynamicMatrix will return a `ShapeError`:
+    /// ```should_panic
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// mat.adjugate().unwrap();
+    /// ```
+    ///
+    /// The adjugate of a DynamicMatrix with a single row would be `[1]`, which can't be expressed with these bounds, so
+    /// a DynamicMatrix with fewer than two rows will return a `ShapeError`:
+    /// ```
+    /// # use dynamic_matrix::DynamicMatrix;
+    /// let mat = DynamicMatrix::new([[7]]);
+    ///
+    /// assert_eq!(
+    ///     mat.adjugate().unwrap_err().to_string(),
+    ///     "The operation performed expected at least 2 rows but the matrix has 1.\n"
+    /// );
+    /// ```
+    pub fn adjugate(&self) -> Result<DynamicMatrix<T>, ShapeError>
+    where
+        T: Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Copy + Default,
+    {
+        self.ensure_square()?;
+
+        let n = self.rows;
+        if n < 2 {
+            return Err(ShapeError::new_min_rows_error(n, 2));
+        }
+
+        let mut data = Vec::with_capacity(n * n);
+        let mut minor_rows = Vec::with_capacity(n);
+        let mut minor_cols = Vec::with_capacity(n);
+        for row in 0..n {
+            for col in 0..n {
+                // The element at (row, col) of the adjugate is the cofactor at (col, row)
+                minor_rows.clear();
+                minor_rows.extend((0..n).filter(|&r| r != col));
+                minor_cols.clear();
+                minor_cols.extend((0..n).filter(|&c| c != row));
+
+                let minor = self.cofactor_expansion(&minor_rows, &minor_cols);
+                data.push(if (row + col) % 2 == 0 {
+                    minor
+                } else {
+                    T::default() - minor
+                });
+            }
+        }
+
+        Ok(DynamicMatrix {
+            data,
+            rows: n,
+            cols: n,
+        })
+    }
+
+    /// Sums the elements on the main diagonal of a square DynamicMatrix
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    ///
+    /// assert_eq!(mat.trace().unwrap(), 15);
+    ///
+    /// let empty: DynamicMatrix<isize> = DynamicMatrix::new_with_cols(0);
+    /// assert_eq!(empty.trace().unwrap(), 0);
+    /// ```
+    ///
+    /// Computing the trace of a non-square DynamicMatrix will return a `ShapeError`:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// assert_eq!(
+    ///     mat.trace().unwrap_err().to_string(),
+    ///     "The operation performed expected 2 cols but the matrix has 3.\n"
+    /// );
+    /// ```
+    pub fn trace(&self) -> Result<T, ShapeError>
+    where
+        T: Add<Output = T> + Clone + Default,
+    {
+        self.ensure_square()?;
+
+        Ok(self
+            .diagonal()
+            .into_iter()
+            .fold(T::default(), |acc, e| acc + e.clone()))
+    }
+
+    /// Expands the determinant of the minor made of the given rows and columns along its first row
+    fn cofactor_expansion(&self, rows: &[usize], cols: &[usize]) -> T
+    where
+        T: Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Copy + Default,
+    {
+        let row = rows[0];
+        if cols.len() == 1 {
+            return self.data[row * self.cols + cols[0]];
+        }
+
+        let mut det = T::default();
+        let mut minor_cols = Vec::with_capacity(cols.len() - 1);
+        for (i, &col) in cols.iter().enumerate() {
+            minor_cols.clear();
+            minor_cols.extend(cols.iter().copied().filter(|&c| c != col));
+
+            let term =
+                self.data[row * self.cols + col] * self.cofactor_expansion(&rows[1..], &minor_cols);
+            det = if i % 2 == 0 { det + term } else { det - term };
+        }
+
+        det
+    }
+}
+
+impl DynamicMatrix<i8> {
+    /// Constructs the cofactor sign pattern of the given shape, `1` where the row and column indices add up to an
+    /// even number and `-1` elsewhere
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let signs = DynamicMatrix::sign_pattern(3, 3);
+    ///
+    /// assert_eq!(signs.shape(), (3, 3));
+    /// assert_eq!(signs.as_slice(), &[1, -1, 1, -1, 1, -1, 1, -1, 1]);
+    ///
+    /// assert_eq!(DynamicMatrix::sign_pattern(2, 3).as_slice(), &[1, -1, 1, -1, 1, -1]);
+    /// ```
+    pub fn sign_pattern(rows: usize, cols: usize) -> DynamicMatrix<i8> {
+        DynamicMatrix {
+            data: (0..rows * cols)
+                .map(|i| {
+                    if (i / cols + i % cols) % 2 == 0 {
+                        1
+                    } else {
+                        -1
+                    }
+                })
+                .collect(),
+            rows,
+            cols,
+        }
+    }
+}
+
+impl<T: Add<Output = T>> Add for DynamicMatrix<T> {
+    type Output = Result<DynamicMatrix<T>, ShapeError>;
+
+    /// Adds two DynamicMatrix element-wise, consuming both of them
+    ///
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1, 2; 3, 4];
+    /// let b = dynamic_matrix![4, 3; 2, 1];
+    ///
+    /// let sum = (a + b).unwrap();
+    ///
+    /// assert_eq!(sum.as_slice(), &[5, 5, 5, 5]);
+    /// ```
+    ///
+    /// Adding matrices of different shapes will return a `ShapeError`:
+    /// ```
+    /// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+    /// let a = dynamic_matrix![1, 2; 3, 4];
+    /// let b = dynamic_matrix![1, 2, 3; 4, 5, 6];
+    ///
+    /// assert!((a + b).is_err());
+    /// ```
+    fn add(self, other: Self) -> Self::Output {
+        if self.shape() != other.shape() {
+            return Err(ShapeError::new(other.shape(), self.shape()));
+        }
+
+        Ok(DynamicMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .into_iter()
+                .zip(other.data)
+                .map(|(a, b)| a + b)
+                .collect(),
+        })
+    }
+}
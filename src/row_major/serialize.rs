@@ -0,0 +1,68 @@
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::DynamicMatrix;
+
+/// Serializes the DynamicMatrix as a struct with its elements in row-major order under `data` and the number of
+/// columns under `cols`
+///
+/// ```
+/// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+/// let mat = dynamic_matrix![1, 2, 3; 4, 5, 6];
+///
+/// assert_eq!(
+///     serde_json::to_string(&mat).unwrap(),
+///     r#"{"data":[1,2,3,4,5,6],"cols":3}"#
+/// );
+/// ```
+impl<T: Serialize> Serialize for DynamicMatrix<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("DynamicMatrix", 2)?;
+        state.serialize_field("data", &self.data)?;
+        state.serialize_field("cols", &self.cols)?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "DynamicMatrix")]
+struct RawMatrix<T> {
+    data: Vec<T>,
+    cols: usize,
+}
+
+/// Deserializes a DynamicMatrix from a struct with `data` and `cols` fields, inferring the number of rows from the
+/// number of elements
+///
+/// Since the number of rows is inferred, a DynamicMatrix with no columns always deserializes with no rows.
+///
+/// ```
+/// # use dynamic_matrix::{dynamic_matrix, DynamicMatrix};
+/// let mat = dynamic_matrix![1.5, 2.5; 3.5, 4.5; 5.5, 6.5];
+///
+/// let json = serde_json::to_string(&mat).unwrap();
+/// let back: DynamicMatrix<f64> = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(back.shape(), (3, 2));
+/// assert_eq!(back.as_slice(), mat.as_slice());
+///
+/// let empty: DynamicMatrix<f64> = serde_json::from_str(r#"{"data":[],"cols":4}"#).unwrap();
+/// assert_eq!(empty.shape(), (0, 4));
+/// ```
+///
+/// Deserializing a number of elements that is not a multiple of `cols` will return an error:
+/// ```
+/// # use dynamic_matrix::DynamicMatrix;
+/// let res: Result<DynamicMatrix<i32>, _> = serde_json::from_str(r#"{"data":[1,2,3,4,5],"cols":2}"#);
+///
+/// assert!(res
+///     .unwrap_err()
+///     .to_string()
+///     .starts_with("Attempted reshaping 5 elements into rows of 2 cols."));
+/// ```
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for DynamicMatrix<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RawMatrix { data, cols } = RawMatrix::deserialize(deserializer)?;
+
+        DynamicMatrix::from_vec(data, cols).map_err(de::Error::custom)
+    }
+}